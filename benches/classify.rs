@@ -0,0 +1,38 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use floating_cat::*;
+
+/// A mix of every category in no particular order, so branches can't be predicted.
+fn inputs() -> Vec<f64> {
+    let mut bits = 0u64;
+    (0..4096)
+        .map(|_| {
+            bits = bits.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            // Keep most exponents near 1.0, where all three finite variants occur.
+            let exponent = (bits >> 52) % 64 + 1000;
+            f64::from_bits((bits & !(0x7ff << 52)) | (exponent << 52))
+        })
+        .collect()
+}
+
+fn classify(c: &mut Criterion) {
+    let xs = inputs();
+    let mut group = c.benchmark_group("classify");
+    group.bench_function("category", |b| {
+        b.iter(|| {
+            for x in &xs {
+                black_box(black_box(*x).category());
+            }
+        })
+    });
+    group.bench_function("category_branchless", |b| {
+        b.iter(|| {
+            for x in &xs {
+                black_box(category_branchless(black_box(*x)));
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, classify);
+criterion_main!(benches);
@@ -0,0 +1,774 @@
+//! Classifying many floats at once.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{CatFloat, Category};
+
+/// Classifies every float in `xs`, in order.
+///
+/// # Examples:
+/// ```rust
+/// # use floating_cat::*;
+/// let cats = batch::categorize_slice(&[1.0, 0.5]);
+/// assert_eq!(cats, [CatFloat::IntegerLike(1.0), CatFloat::FractionLike(0.5)]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn categorize_slice(xs: &[f64]) -> Vec<CatFloat> {
+    xs.iter().map(Category::category).collect()
+}
+
+/// Classifies every float in `xs`, in order, leaving out NaNs and infinities.
+///
+/// The number dropped is `xs.len()` minus the length of the result.
+///
+/// # Examples:
+/// ```rust
+/// # use floating_cat::*;
+/// let cats = batch::categorize_filter_finite(&[1.0, f64::NAN, 0.5]);
+/// assert_eq!(cats, [CatFloat::IntegerLike(1.0), CatFloat::FractionLike(0.5)]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn categorize_filter_finite(xs: &[f64]) -> Vec<CatFloat> {
+    xs.iter()
+        .map(Category::category)
+        .filter(CatFloat::is_finite)
+        .collect()
+}
+
+/// Classifies `start`, `start + step`, `start + 2.0 * step`, ... for every value below `end`.
+///
+/// Each value is computed as `start + i * step` rather than by repeated addition, so rounding
+/// errors don't pile up. Returns an empty `Vec` if `step` isn't positive or any argument
+/// isn't finite.
+///
+/// # Examples:
+/// ```rust
+/// # use floating_cat::*;
+/// let sweep = batch::classify_range(0.0, 1.0, 0.5);
+/// assert_eq!(sweep, [(0.0, 0.0f64.category()), (0.5, 0.5f64.category())]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn classify_range(start: f64, end: f64, step: f64) -> Vec<(f64, CatFloat)> {
+    if !(start.is_finite() && end.is_finite() && step.is_finite() && step > 0.0) {
+        return Vec::new();
+    }
+
+    (0u64..)
+        .map(|i| start + i as f64 * step)
+        .take_while(|x| *x < end)
+        .map(|x| (x, x.category()))
+        .collect()
+}
+
+/// Classifies `xs` and collapses each run of equal consecutive classifications into one
+/// `(classification, run length)` pair.
+///
+/// Runs compare with [`CatFloat`]'s `PartialEq`, so they're runs of equal values, not just
+/// equal variants. Consecutive NaNs always merge, even with different kinds or payloads,
+/// and the run keeps the first one.
+///
+/// # Examples:
+/// ```rust
+/// # use floating_cat::*;
+/// let runs = batch::categorize_dedup_consecutive(&[1.0, 1.0, 0.5]);
+/// assert_eq!(runs, [(CatFloat::IntegerLike(1.0), 2), (CatFloat::FractionLike(0.5), 1)]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn categorize_dedup_consecutive(xs: &[f64]) -> Vec<(CatFloat, usize)> {
+    let mut runs: Vec<(CatFloat, usize)> = Vec::new();
+    for x in xs {
+        let cat = x.category();
+        match runs.last_mut() {
+            Some((last, len)) if *last == cat || (last.is_nan() && cat.is_nan()) => *len += 1,
+            _ => runs.push((cat, 1)),
+        }
+    }
+    runs
+}
+
+/// Classifies a whole slice, element by element, exactly like [`categorize_slice`].
+///
+/// Each element goes through the scalar `f64` impl, so `xs.category()[i] == xs[i].category()`.
+///
+/// # Examples:
+/// ```rust
+/// # use floating_cat::*;
+/// let xs: &[f64] = &[1.0, 0.5];
+/// assert_eq!(xs.category(), [CatFloat::IntegerLike(1.0), CatFloat::FractionLike(0.5)]);
+/// ```
+#[cfg(feature = "alloc")]
+impl Category for [f64] {
+    type D = Vec<CatFloat>;
+
+    fn category(&self) -> Self::D {
+        categorize_slice(self)
+    }
+
+    /// Reuses `out`'s allocation instead of building a new `Vec`.
+    fn classify_into(&self, out: &mut Self::D) {
+        out.clear();
+        out.extend(self.iter().map(Category::category));
+    }
+}
+
+/// Classifies every float in `xs` like [`categorize_slice`], spread across rayon's thread pool.
+///
+/// The output is in the same order as `xs`.
+///
+/// # Examples:
+/// ```rust
+/// # use floating_cat::*;
+/// let xs = [1.0, 0.5, 1.5];
+/// assert_eq!(batch::categorize_par(&xs), batch::categorize_slice(&xs));
+/// ```
+#[cfg(feature = "rayon")]
+pub fn categorize_par(xs: &[f64]) -> Vec<CatFloat> {
+    use rayon::prelude::*;
+
+    xs.par_iter().map(Category::category).collect()
+}
+
+/// Classifies a fixed-size array into a fixed-size array, without allocating.
+///
+/// # Examples:
+/// ```rust
+/// # use floating_cat::*;
+/// let cats = batch::categorize_array(&[1.0, 0.5]);
+/// assert_eq!(cats, [CatFloat::IntegerLike(1.0), CatFloat::FractionLike(0.5)]);
+/// ```
+pub fn categorize_array<const N: usize>(xs: &[f64; N]) -> [CatFloat; N] {
+    xs.map(|x| x.category())
+}
+
+/// Classifies a fixed-size array element by element, exactly like [`categorize_array`].
+///
+/// # Examples:
+/// ```rust
+/// # use floating_cat::*;
+/// assert_eq!([1.0, 0.5].category(), [CatFloat::IntegerLike(1.0), CatFloat::FractionLike(0.5)]);
+/// ```
+impl<const N: usize> Category for [f64; N] {
+    type D = [CatFloat; N];
+
+    fn category(&self) -> Self::D {
+        categorize_array(self)
+    }
+}
+
+/// Classifies every float in `xs` into the matching slot of `out`, without allocating.
+///
+/// # Panics
+///
+/// Panics if `xs` and `out` have different lengths.
+pub fn categorize_into(xs: &[f64], out: &mut [CatFloat]) {
+    assert_eq!(
+        xs.len(),
+        out.len(),
+        "categorize_into: input and output slices have different lengths"
+    );
+
+    for (x, slot) in xs.iter().zip(out) {
+        *slot = x.category();
+    }
+}
+
+/// Classifies every float in `xs` like [`categorize_slice`], four lanes at a time.
+///
+/// `trunc` and `fract` are computed with SIMD; lanes holding NaN, infinities, zeros,
+/// subnormals, or huge integers fall back to the scalar classifier, as does the tail
+/// that doesn't fill a whole chunk. The output is bit-identical to [`categorize_slice`].
+#[cfg(all(feature = "simd", feature = "alloc"))]
+pub fn categorize_slice_simd(xs: &[f64]) -> Vec<CatFloat> {
+    use wide::f64x4;
+
+    const LANES: usize = 4;
+
+    let mut out = Vec::with_capacity(xs.len());
+    let chunks = xs.chunks_exact(LANES);
+    let rest = chunks.remainder();
+
+    for chunk in chunks {
+        let lanes: [f64; LANES] = chunk.try_into().expect("chunks are exactly LANES long");
+        let v = f64x4::from(lanes);
+        let magnitude = v.abs();
+        let int_part = v.trunc();
+        let fract_part = v - int_part;
+
+        let special = (v.is_nan()
+            | magnitude.simd_lt(f64x4::splat(f64::MIN_POSITIVE))
+            | magnitude.simd_ge(f64x4::splat(crate::HUGE_INTEGER_LIMIT)))
+        .to_bitmask();
+        let fract_is_zero = fract_part.simd_eq(f64x4::ZERO).to_bitmask();
+        let int_is_zero = int_part.simd_eq(f64x4::ZERO).to_bitmask();
+        let (int_part, fract_part) = (int_part.to_array(), fract_part.to_array());
+
+        for lane in 0..LANES {
+            let bit = 1 << lane;
+            out.push(if special & bit != 0 {
+                lanes[lane].category()
+            } else if fract_is_zero & bit != 0 {
+                CatFloat::IntegerLike(int_part[lane])
+            } else if int_is_zero & bit != 0 {
+                CatFloat::FractionLike(fract_part[lane])
+            } else {
+                CatFloat::IntegerAndFractionalPart(int_part[lane], fract_part[lane])
+            });
+        }
+    }
+
+    out.extend(rest.iter().map(Category::category));
+    out
+}
+
+/// How many floats fell into each category; see [`count_categories`].
+#[derive(Debug, Default, PartialEq, Eq, Copy, Clone)]
+pub struct CategoryCounts {
+    pub integer_like: usize,
+    pub fraction_like: usize,
+    /// [`CatFloat::IntegerAndFractionalPart`]s.
+    pub mixed: usize,
+    pub nan: usize,
+    pub infinity: usize,
+    pub zero: usize,
+    pub subnormal: usize,
+    pub huge_integer: usize,
+}
+
+impl CategoryCounts {
+    /// Counts one more classification.
+    pub fn record(&mut self, cat: &CatFloat) {
+        let count = match cat {
+            CatFloat::IntegerLike(..) => &mut self.integer_like,
+            CatFloat::FractionLike(..) => &mut self.fraction_like,
+            CatFloat::IntegerAndFractionalPart(..) => &mut self.mixed,
+            CatFloat::Nan { .. } => &mut self.nan,
+            CatFloat::Infinity(..) => &mut self.infinity,
+            CatFloat::Zero(..) => &mut self.zero,
+            CatFloat::Subnormal(..) => &mut self.subnormal,
+            CatFloat::HugeInteger(..) => &mut self.huge_integer,
+        };
+        *count += 1;
+    }
+
+    /// The number of floats counted, across every category.
+    pub fn total(&self) -> usize {
+        self.integer_like
+            + self.fraction_like
+            + self.mixed
+            + self.nan
+            + self.infinity
+            + self.zero
+            + self.subnormal
+            + self.huge_integer
+    }
+}
+
+/// Sums `xs` with compensated (Kahan-Babuška) summation, then classifies the sum.
+///
+/// Compensation tracks the rounding error of each addition, so sums that should be whole,
+/// like ten `0.1`s, classify as [`CatFloat::IntegerLike`] rather than just under.
+/// If any term is NaN or infinite, or the sum overflows, the result is what plain summation
+/// gives: [`CatFloat::Nan`], or [`CatFloat::Infinity`] with the appropriate sign.
+/// Zeros follow IEEE-754 too: the sum is only a negative [`CatFloat::Zero`] if every term is
+/// `-0.0`, and an empty slice sums to a positive zero.
+///
+/// # Examples:
+/// ```rust
+/// # use floating_cat::*;
+/// assert_eq!(batch::classify_sum(&[0.1; 10]), CatFloat::IntegerLike(1.0));
+/// ```
+pub fn classify_sum(xs: &[f64]) -> CatFloat {
+    if xs.iter().any(|x| !x.is_finite()) {
+        return xs.iter().sum::<f64>().category();
+    }
+
+    // -0.0 is the identity for addition, so a sum of only negative zeros stays negative.
+    let mut sum = if xs.is_empty() { 0.0 } else { -0.0f64 };
+    let mut compensation = 0.0f64;
+    for &x in xs {
+        let t = sum + x;
+        if !t.is_finite() {
+            // Overflowed; the compensation would only turn this into NaN.
+            return t.category();
+        }
+        // Whichever of the two is larger in magnitude loses the low bits of the other.
+        if sum.abs() >= x.abs() {
+            compensation += (sum - t) + x;
+        } else {
+            compensation += (x - t) + sum;
+        }
+        sum = t;
+    }
+    if compensation == 0.0 {
+        // Adding a positive zero would drop a negative zero's sign.
+        return sum.category();
+    }
+    (sum + compensation).category()
+}
+
+/// Tallies how many floats in `xs` fall into each category.
+///
+/// # Examples:
+/// ```rust
+/// # use floating_cat::*;
+/// let counts = batch::count_categories(&[1.0, 0.5, 2.0]);
+/// assert_eq!(counts.integer_like, 2);
+/// assert_eq!(counts.total(), 3);
+/// ```
+pub fn count_categories(xs: &[f64]) -> CategoryCounts {
+    let mut counts = CategoryCounts::default();
+    for x in xs {
+        counts.record(&x.category());
+    }
+    counts
+}
+
+/// Splits `xs` into one `Vec` per category, keeping each bucket in input order.
+///
+/// # Examples:
+/// ```rust
+/// # use floating_cat::*;
+/// let parts = batch::partition_by_category(&[1.0, 0.5, 2.0, f64::NAN]);
+/// assert_eq!(parts.integer_like, [1.0, 2.0]);
+/// assert_eq!(parts.fraction_like, [0.5]);
+/// assert_eq!(parts.nan.len(), 1);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn partition_by_category(xs: &[f64]) -> CategoryPartition {
+    let mut buckets = CategoryPartition::default();
+    for &x in xs {
+        let bucket = match x.category() {
+            CatFloat::IntegerLike(..) => &mut buckets.integer_like,
+            CatFloat::FractionLike(..) => &mut buckets.fraction_like,
+            CatFloat::IntegerAndFractionalPart(..) => &mut buckets.mixed,
+            CatFloat::Nan { .. } => &mut buckets.nan,
+            CatFloat::Infinity(..) => &mut buckets.infinity,
+            CatFloat::Zero(..) => &mut buckets.zero,
+            CatFloat::Subnormal(..) => &mut buckets.subnormal,
+            CatFloat::HugeInteger(..) => &mut buckets.huge_integer,
+        };
+        bucket.push(x);
+    }
+    buckets
+}
+
+/// The floats in each category, from [`partition_by_category`].
+///
+/// The buckets match [`CategoryCounts`]' fields, so `counts.nan == partition.nan.len()`.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct CategoryPartition {
+    pub integer_like: Vec<f64>,
+    pub fraction_like: Vec<f64>,
+    /// Values classified as [`CatFloat::IntegerAndFractionalPart`].
+    pub mixed: Vec<f64>,
+    pub nan: Vec<f64>,
+    pub infinity: Vec<f64>,
+    pub zero: Vec<f64>,
+    pub subnormal: Vec<f64>,
+    pub huge_integer: Vec<f64>,
+}
+
+/// Tallies the categories in each column of `rows`, so `result[j]` counts every `rows[i][j]`.
+///
+/// Returns [`RaggedRows`] if the rows don't all have the same length.
+/// No rows at all means no columns, so that's an empty `Vec`.
+///
+/// # Examples:
+/// ```rust
+/// # use floating_cat::*;
+/// let rows = [vec![1.0, 0.5], vec![2.0, 0.25]];
+/// let columns = batch::classify_columns(&rows).unwrap();
+/// assert_eq!(columns[0].integer_like, 2);
+/// assert_eq!(columns[1].fraction_like, 2);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn classify_columns(rows: &[Vec<f64>]) -> Result<Vec<CategoryCounts>, RaggedRows> {
+    let width = rows.first().map_or(0, Vec::len);
+    if let Some(row) = rows.iter().position(|r| r.len() != width) {
+        return Err(RaggedRows {
+            row,
+            expected: width,
+            found: rows[row].len(),
+        });
+    }
+
+    let mut columns = alloc::vec![CategoryCounts::default(); width];
+    for row in rows {
+        for (counts, x) in columns.iter_mut().zip(row) {
+            counts.record(&x.category());
+        }
+    }
+    Ok(columns)
+}
+
+/// The error from [`classify_columns`] when a row's length differs from the first row's.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct RaggedRows {
+    /// The index of the first row with the wrong length.
+    pub row: usize,
+    /// The length of the first row.
+    pub expected: usize,
+    /// The length of the offending row.
+    pub found: usize,
+}
+
+impl core::fmt::Display for RaggedRows {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "row {} has {} columns, but the first row has {}",
+            self.row, self.found, self.expected
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RaggedRows {}
+
+/// Running tallies over a stream of floats, without storing them.
+///
+/// # Examples:
+/// ```rust
+/// # use floating_cat::*;
+/// let mut acc = batch::CategoryAccumulator::default();
+/// for x in [1.25, 2.0, -0.5] {
+///     acc.push(x);
+/// }
+/// let summary = acc.finish();
+/// assert_eq!(summary.counts.total(), 3);
+/// assert_eq!(summary.min_fraction, Some(-0.5));
+/// assert_eq!(summary.max_fraction, Some(0.25));
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct CategoryAccumulator {
+    summary: CategorySummary,
+}
+
+impl CategoryAccumulator {
+    /// Classifies `x` and folds it into the running tallies.
+    pub fn push(&mut self, x: f64) {
+        let cat = x.category();
+        self.summary.counts.record(&cat);
+
+        if let Some(fract) = cat.fractional_part().filter(|f| *f != 0.0) {
+            let min = self.summary.min_fraction.get_or_insert(fract);
+            *min = min.min(fract);
+            let max = self.summary.max_fraction.get_or_insert(fract);
+            *max = max.max(fract);
+        }
+    }
+
+    /// The tallies so far.
+    pub fn finish(self) -> CategorySummary {
+        self.summary
+    }
+}
+
+/// What a [`CategoryAccumulator`] saw.
+#[derive(Debug, Default, PartialEq, Copy, Clone)]
+pub struct CategorySummary {
+    pub counts: CategoryCounts,
+    /// The smallest nonzero fractional part pushed, signed, or `None` if there were none.
+    pub min_fraction: Option<f64>,
+    /// The largest nonzero fractional part pushed, signed, or `None` if there were none.
+    pub max_fraction: Option<f64>,
+}
+
+#[test]
+fn batch_matches_scalar() {
+    use crate::*;
+
+    let xs = [1.0, 0.5, 1.5, -0.0, 5e-324, f64::NAN, f64::NEG_INFINITY];
+
+    let mut out = [CatFloat::Zero(Sign::Positive); 7];
+    categorize_into(&xs, &mut out);
+    for (x, c) in xs.iter().zip(out) {
+        assert_eq!(c.to_f64().to_bits(), x.category().to_f64().to_bits());
+    }
+
+    #[cfg(feature = "alloc")]
+    for (x, c) in xs.iter().zip(categorize_slice(&xs)) {
+        assert_eq!(c.to_f64().to_bits(), x.category().to_f64().to_bits());
+    }
+}
+
+#[test]
+#[should_panic(expected = "different lengths")]
+fn categorize_into_rejects_length_mismatch() {
+    use crate::Sign;
+
+    let mut out = [CatFloat::Zero(Sign::Positive); 1];
+    categorize_into(&[1.0, 2.0], &mut out);
+}
+
+#[cfg(all(feature = "simd", feature = "alloc"))]
+#[test]
+fn simd_matches_scalar() {
+    use crate::*;
+
+    // A fixed-seed xorshift, so failures are reproducible.
+    let mut state: u64 = 0x9e37_79b9_7f4a_7c15;
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+
+    let mut xs: Vec<f64> = (0..4096).map(|_| f64::from_bits(next())).collect();
+    xs.extend((0..4096).map(|_| (next() as i64 as f64) / (1u64 << (next() % 64)) as f64));
+    xs.extend([0.0, -0.0, 5e-324, 1e300, f64::NAN, f64::INFINITY, 1.5]);
+
+    let bits = |cats: Vec<CatFloat>| -> Vec<(core::mem::Discriminant<CatFloat>, u64, u64)> {
+        cats.iter()
+            .map(|c| {
+                let (int, fract) = match *c {
+                    CatFloat::IntegerAndFractionalPart(int, fract) => (int, fract),
+                    _ => (c.to_f64(), 0.0),
+                };
+                (core::mem::discriminant(c), int.to_bits(), fract.to_bits())
+            })
+            .collect()
+    };
+    assert_eq!(
+        bits(categorize_slice_simd(&xs)),
+        bits(categorize_slice(&xs))
+    );
+}
+
+#[test]
+fn count_categories_tallies() {
+    let counts = count_categories(&[1.0, 0.5, 1.5, f64::NAN, f64::INFINITY, 2.0]);
+    assert_eq!(
+        counts,
+        CategoryCounts {
+            integer_like: 2,
+            fraction_like: 1,
+            mixed: 1,
+            nan: 1,
+            infinity: 1,
+            ..CategoryCounts::default()
+        }
+    );
+    assert_eq!(counts.total(), 6);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn slice_category_matches_scalar() {
+    let xs: &[f64] = &[1.0, 0.5, -1.5, f64::INFINITY, 0.0];
+    let cats = xs.category();
+
+    assert_eq!(cats.len(), xs.len());
+    for (x, cat) in xs.iter().zip(&cats) {
+        assert_eq!(*cat, x.category());
+    }
+
+    let mut out = alloc::vec![CatFloat::default(); 32];
+    xs.classify_into(&mut out);
+    assert_eq!(out, cats);
+}
+
+#[test]
+fn accumulator_summarizes_stream() {
+    let xs = [1.25, 2.0, -0.5, 3.75, f64::NAN, 0.0];
+
+    let mut acc = CategoryAccumulator::default();
+    for x in xs {
+        acc.push(x);
+    }
+    let summary = acc.finish();
+
+    assert_eq!(summary.counts, count_categories(&xs));
+    assert_eq!(summary.min_fraction, Some(-0.5));
+    assert_eq!(summary.max_fraction, Some(0.75));
+
+    let empty = CategoryAccumulator::default().finish();
+    assert_eq!(empty.counts.total(), 0);
+    assert_eq!(empty.min_fraction, None);
+}
+
+#[test]
+#[cfg(feature = "rayon")]
+fn parallel_matches_serial() {
+    let xs: Vec<f64> = (0..1_000_000).map(|i| f64::from(i) * 0.37 - 1e5).collect();
+    assert_eq!(categorize_par(&xs), categorize_slice(&xs));
+}
+
+#[test]
+fn categorize_array_matches_scalar() {
+    let xs = [1.0, 0.5, -1.5, f64::NEG_INFINITY];
+    let cats = categorize_array(&xs);
+
+    for (x, cat) in xs.iter().zip(cats) {
+        assert_eq!(cat, x.category());
+    }
+    assert_eq!(categorize_array(&[]), []);
+
+    assert_eq!(
+        [1.0, 0.5, 1.5].category(),
+        [
+            CatFloat::IntegerLike(1.0),
+            CatFloat::FractionLike(0.5),
+            CatFloat::IntegerAndFractionalPart(1.0, 0.5),
+        ]
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn filter_finite_drops_special_values() {
+    let xs = [1.0, f64::NAN, 0.5, f64::INFINITY];
+    let cats = categorize_filter_finite(&xs);
+
+    assert_eq!(
+        cats,
+        [CatFloat::IntegerLike(1.0), CatFloat::FractionLike(0.5)]
+    );
+    assert_eq!(xs.len() - cats.len(), 2);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn classify_range_sweeps() {
+    let sweep = classify_range(0.0, 2.0, 0.5);
+    let xs: Vec<f64> = sweep.iter().map(|(x, _)| *x).collect();
+    assert_eq!(xs, [0.0, 0.5, 1.0, 1.5]);
+    assert!(sweep[0].1.is_zero());
+    assert!(sweep[1].1.is_fraction_like());
+    assert!(sweep[2].1.is_integer_like());
+    assert!(sweep[3].1.is_integer_and_fractional_part());
+
+    assert_eq!(classify_range(0.0, 1.0, 0.1).len(), 10);
+    assert!(classify_range(0.0, 2.0, 0.0).is_empty());
+    assert!(classify_range(0.0, 2.0, -0.5).is_empty());
+    assert!(classify_range(0.0, f64::INFINITY, 0.5).is_empty());
+    assert!(classify_range(f64::NAN, 2.0, 0.5).is_empty());
+    assert!(classify_range(2.0, 0.0, 0.5).is_empty());
+}
+
+#[test]
+fn classify_sum_compensates() {
+    use crate::Sign;
+
+    let tenths = [0.1; 10];
+    assert_ne!(tenths.iter().sum::<f64>(), 1.0);
+    assert_eq!(classify_sum(&tenths), CatFloat::IntegerLike(1.0));
+
+    assert_eq!(
+        classify_sum(&[1e100, 1.0, -1e100]),
+        CatFloat::IntegerLike(1.0)
+    );
+    assert_eq!(classify_sum(&[]), CatFloat::Zero(Sign::Positive));
+    assert!(classify_sum(&[1.0, f64::NAN]).is_nan());
+    assert!(classify_sum(&[f64::INFINITY, f64::NEG_INFINITY]).is_nan());
+    assert!(classify_sum(&[1.0, f64::NEG_INFINITY]).is_negative_infinity());
+    assert!(classify_sum(&[f64::MAX, f64::MAX]).is_positive_infinity());
+
+    assert_eq!(classify_sum(&[-0.0]), CatFloat::Zero(Sign::Negative));
+    assert_eq!(classify_sum(&[-0.0, -0.0]), CatFloat::Zero(Sign::Negative));
+    assert_eq!(classify_sum(&[-0.0, 0.0]), CatFloat::Zero(Sign::Positive));
+    assert_eq!(classify_sum(&[1.0, -1.0]), CatFloat::Zero(Sign::Positive));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn classify_columns_counts_each_column() {
+    use alloc::vec;
+
+    let rows = [vec![1.0, 0.5], vec![2.5, f64::NAN], vec![-3.0, 0.25]];
+    let columns = classify_columns(&rows).unwrap();
+    assert_eq!(columns.len(), 2);
+    assert_eq!(
+        columns[0],
+        CategoryCounts {
+            integer_like: 2,
+            mixed: 1,
+            ..Default::default()
+        }
+    );
+    assert_eq!(
+        columns[1],
+        CategoryCounts {
+            fraction_like: 2,
+            nan: 1,
+            ..Default::default()
+        }
+    );
+
+    assert_eq!(classify_columns(&[]), Ok(Vec::new()));
+    assert_eq!(
+        classify_columns(&[vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0]]),
+        Err(RaggedRows {
+            row: 2,
+            expected: 2,
+            found: 1
+        })
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn categorize_dedup_consecutive_counts_runs() {
+    let runs = categorize_dedup_consecutive(&[1.0, 1.0, 0.5, 0.5, 0.5, 1.5]);
+    assert_eq!(
+        runs,
+        [
+            (CatFloat::IntegerLike(1.0), 2),
+            (CatFloat::FractionLike(0.5), 3),
+            (CatFloat::IntegerAndFractionalPart(1.0, 0.5), 1),
+        ]
+    );
+
+    let signaling = f64::from_bits(0x7ff0_0000_0000_0001);
+    let runs = categorize_dedup_consecutive(&[f64::NAN, -f64::NAN, signaling, 2.0, 3.0]);
+    assert_eq!(runs.len(), 3);
+    assert!(matches!(
+        runs[0].0,
+        CatFloat::Nan {
+            kind: crate::NanKind::Quiet,
+            ..
+        }
+    ));
+    assert_eq!(runs[0].1, 3);
+    assert_eq!(runs[1], (CatFloat::IntegerLike(2.0), 1));
+
+    assert!(categorize_dedup_consecutive(&[]).is_empty());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn partition_by_category_keeps_order() {
+    let xs = [
+        3.0,
+        0.5,
+        f64::NAN,
+        -1.5,
+        1.0,
+        -0.25,
+        f64::NEG_INFINITY,
+        2.5,
+        -0.0,
+        5e-324,
+        1e40,
+    ];
+    let parts = partition_by_category(&xs);
+    assert_eq!(parts.integer_like, [3.0, 1.0]);
+    assert_eq!(parts.fraction_like, [0.5, -0.25]);
+    assert_eq!(parts.mixed, [-1.5, 2.5]);
+    assert_eq!(parts.nan.len(), 1);
+    assert!(parts.nan[0].is_nan());
+    assert_eq!(parts.infinity, [f64::NEG_INFINITY]);
+    assert_eq!(parts.zero.len(), 1);
+    assert!(parts.zero[0].is_sign_negative());
+    assert_eq!(parts.subnormal, [5e-324]);
+    assert_eq!(parts.huge_integer, [1e40]);
+
+    let counts = count_categories(&xs);
+    assert_eq!(counts.mixed, parts.mixed.len());
+    assert_eq!(counts.total(), xs.len());
+
+    assert_eq!(partition_by_category(&[]), CategoryPartition::default());
+}
@@ -0,0 +1,64 @@
+//! A float stored alongside its classification.
+
+use crate::{CatFloat, Category};
+
+/// An `f64` that's classified once, when it's stored, rather than on every use.
+///
+/// # Examples:
+/// ```rust
+/// # use floating_cat::*;
+/// let mut x = CachedCategory::new(1.5);
+/// assert_eq!(x.get(), CatFloat::IntegerAndFractionalPart(1.0, 0.5));
+///
+/// x.set(0.5);
+/// assert_eq!(x.get(), CatFloat::FractionLike(0.5));
+/// ```
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct CachedCategory {
+    value: f64,
+    cached: CatFloat,
+}
+
+impl CachedCategory {
+    /// Stores `value` and classifies it.
+    pub fn new(value: f64) -> Self {
+        Self {
+            value,
+            cached: value.category(),
+        }
+    }
+
+    /// The stored float.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// The stored float's classification, without recomputing it.
+    pub fn get(&self) -> CatFloat {
+        self.cached
+    }
+
+    /// Replaces the stored float, classifying the new one.
+    pub fn set(&mut self, value: f64) {
+        *self = Self::new(value);
+    }
+}
+
+impl From<f64> for CachedCategory {
+    fn from(value: f64) -> Self {
+        Self::new(value)
+    }
+}
+
+#[test]
+fn cache_matches_fresh_classification() {
+    let mut x = CachedCategory::new(-2.25);
+    assert_eq!(x.value(), -2.25);
+    assert_eq!(x.get(), (-2.25f64).category());
+
+    x.set(f64::INFINITY);
+    assert_eq!(x.value(), f64::INFINITY);
+    assert_eq!(x.get(), f64::INFINITY.category());
+
+    assert_eq!(CachedCategory::from(3.0).get(), CatFloat::IntegerLike(3.0));
+}
@@ -0,0 +1,136 @@
+//! Classifying `half::f16`s, behind the `half` feature.
+//!
+//! There is no `f128` path yet: the primitive is still unstable, and this crate builds on stable.
+
+use half::f16;
+use num::traits::float::FloatCore;
+
+use crate::{Category, NanKind, Sign};
+
+/// The top mantissa bit of an `f16`, which is set for quiet NaNs.
+const QUIET_NAN_BIT: u16 = 1 << 9;
+
+/// The category of an [`f16`]; the half-precision counterpart of [`CatFloat`].
+///
+/// The parts are stored as `f16`, exactly as `trunc` and `fract` produced them.
+/// There's no `HugeInteger` variant, since even `f16::MAX` is only 65504.
+///
+/// [`CatFloat`]: crate::CatFloat
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum CatF16 {
+    /// For `f16`s like `1.0`, `-100.0`, with no fractional part.
+    IntegerLike(f16),
+
+    /// For `f16`s like `0.5` or `-0.25`, where there's no integer part.
+    FractionLike(f16),
+
+    /// The Integer and Fractional parts of an f16, in that order.
+    IntegerAndFractionalPart(f16, f16),
+
+    /// The Float was NaN, either quiet or signaling.
+    Nan(NanKind),
+
+    /// The Float was Infinity, either positive or negative.
+    Infinity(Sign),
+
+    /// The Float was exactly zero, either `0.0` or `-0.0`.
+    Zero(Sign),
+
+    /// For subnormal (denormal) floats, which are too small to be normal.
+    Subnormal(f16),
+}
+
+impl CatF16 {
+    /// Returns `true` if the Classified float is [`IntegerLike`].
+    ///
+    /// [`IntegerLike`]: CatF16::IntegerLike
+    #[must_use]
+    pub fn is_integer_like(&self) -> bool {
+        matches!(self, Self::IntegerLike(..))
+    }
+
+    /// Returns `true` if the Classified float is [`FractionLike`].
+    ///
+    /// [`FractionLike`]: CatF16::FractionLike
+    #[must_use]
+    pub fn is_fraction_like(&self) -> bool {
+        matches!(self, Self::FractionLike(..))
+    }
+
+    /// Returns `true` if the Classified float is [`IntegerAndFractionalPart`].
+    ///
+    /// [`IntegerAndFractionalPart`]: CatF16::IntegerAndFractionalPart
+    #[must_use]
+    pub fn is_integer_and_fractional_part(&self) -> bool {
+        matches!(self, Self::IntegerAndFractionalPart(..))
+    }
+
+    /// Returns `true` if the Classified float is [`Nan`].
+    ///
+    /// [`Nan`]: CatF16::Nan
+    #[must_use]
+    pub fn is_nan(&self) -> bool {
+        matches!(self, Self::Nan(..))
+    }
+}
+
+impl Category for f16 {
+    type D = CatF16;
+    fn category(&self) -> Self::D {
+        let sign = if self.is_sign_negative() {
+            Sign::Negative
+        } else {
+            Sign::Positive
+        };
+
+        if self.is_infinite() {
+            return CatF16::Infinity(sign);
+        }
+        if self.is_nan() {
+            if self.to_bits() & QUIET_NAN_BIT == 0 {
+                return CatF16::Nan(NanKind::Signaling);
+            }
+            return CatF16::Nan(NanKind::Quiet);
+        }
+        if *self == f16::ZERO {
+            return CatF16::Zero(sign);
+        }
+        if !self.is_normal() {
+            return CatF16::Subnormal(*self);
+        }
+
+        let int_part: f16 = FloatCore::trunc(*self);
+        let fract_part: f16 = FloatCore::fract(*self);
+
+        if fract_part == f16::ZERO {
+            CatF16::IntegerLike(int_part)
+        } else if int_part == f16::ZERO {
+            CatF16::FractionLike(fract_part)
+        } else {
+            CatF16::IntegerAndFractionalPart(int_part, fract_part)
+        }
+    }
+}
+
+#[test]
+fn halves_are_classified() {
+    let h = f16::from_f32;
+
+    assert_eq!(
+        h(1.5).category(),
+        CatF16::IntegerAndFractionalPart(h(1.0), h(0.5))
+    );
+    assert_eq!(h(-3.0).category(), CatF16::IntegerLike(h(-3.0)));
+    assert_eq!(h(0.25).category(), CatF16::FractionLike(h(0.25)));
+    assert_eq!(f16::NEG_ZERO.category(), CatF16::Zero(Sign::Negative));
+    assert_eq!(f16::INFINITY.category(), CatF16::Infinity(Sign::Positive));
+    assert_eq!(f16::NAN.category(), CatF16::Nan(NanKind::Quiet));
+    assert_eq!(
+        f16::from_bits(0x7c01).category(),
+        CatF16::Nan(NanKind::Signaling)
+    );
+    assert!(matches!(
+        f16::from_bits(1).category(),
+        CatF16::Subnormal(..)
+    ));
+}
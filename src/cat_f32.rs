@@ -0,0 +1,206 @@
+use num::traits::float::FloatCore;
+
+use crate::{CatFloat, Category, NanKind, Sign};
+
+/// The top mantissa bit of an `f32`, which is set for quiet NaNs.
+const QUIET_NAN_BIT: u32 = 1 << 22;
+/// A signaling NaN: all exponent bits set, quiet bit clear, and a nonzero payload.
+const SIGNALING_NAN_BITS: u32 = 0x7fa0_0000;
+
+/// The category of an `f32`; the `f32` counterpart of [`CatFloat`].
+///
+/// Storing the parts as `f32` keeps them exactly as `trunc` and `fract` produced them,
+/// without widening to `f64`.
+/// There's no `HugeInteger` variant, since even `f32::MAX` fits in a `u128`.
+///
+/// [`CatFloat`]: crate::CatFloat
+#[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CatF32 {
+    /// For `f32`s like `1.0`, `-100.0`, with no fractional part.
+    IntegerLike(f32),
+
+    /// For `f32`s like `0.5` or `-0.002`, where there's no integer part.
+    FractionLike(f32),
+
+    /// The Integer and Fractional parts of an f32, in that order.
+    IntegerAndFractionalPart(f32, f32),
+
+    /// The Float was NaN, either quiet or signaling.
+    Nan(NanKind),
+
+    /// The Float was Infinity, either positive or negative.
+    Infinity(Sign),
+
+    /// The Float was exactly zero, either `0.0` or `-0.0`.
+    Zero(Sign),
+
+    /// For subnormal (denormal) floats, which are too small to be normal.
+    Subnormal(f32),
+}
+
+impl CatF32 {
+    /// Returns `true` if the Classified float is [`IntegerLike`].
+    ///
+    /// [`IntegerLike`]: CatF32::IntegerLike
+    #[must_use]
+    pub fn is_integer_like(&self) -> bool {
+        matches!(self, Self::IntegerLike(..))
+    }
+
+    /// Returns `true` if the Classified float is [`FractionLike`].
+    ///
+    /// [`FractionLike`]: CatF32::FractionLike
+    #[must_use]
+    pub fn is_fraction_like(&self) -> bool {
+        matches!(self, Self::FractionLike(..))
+    }
+
+    /// Returns `true` if the Classified float is [`IntegerAndFractionalPart`].
+    ///
+    /// [`IntegerAndFractionalPart`]: CatF32::IntegerAndFractionalPart
+    #[must_use]
+    pub fn is_integer_and_fractional_part(&self) -> bool {
+        matches!(self, Self::IntegerAndFractionalPart(..))
+    }
+
+    /// Returns `true` if the Classified float is [`Infinity`].
+    ///
+    /// [`Infinity`]: CatF32::Infinity
+    #[must_use]
+    pub fn is_infinity(&self) -> bool {
+        matches!(self, Self::Infinity(..))
+    }
+
+    /// Returns `true` if the Classified float is [`Zero`], of either sign.
+    ///
+    /// [`Zero`]: CatF32::Zero
+    #[must_use]
+    pub fn is_zero(&self) -> bool {
+        matches!(self, Self::Zero(..))
+    }
+
+    /// Returns `true` if the Classified float is [`Subnormal`].
+    ///
+    /// [`Subnormal`]: CatF32::Subnormal
+    #[must_use]
+    pub fn is_subnormal(&self) -> bool {
+        matches!(self, Self::Subnormal(..))
+    }
+
+    /// Returns `true` if the Classified float is [`Nan`].
+    ///
+    /// [`Nan`]: CatF32::Nan
+    #[must_use]
+    pub fn is_nan(&self) -> bool {
+        matches!(self, Self::Nan(..))
+    }
+
+    /// Rebuilds the `f32` that was classified.
+    pub fn to_f32(&self) -> f32 {
+        match *self {
+            Self::IntegerLike(n) | Self::FractionLike(n) | Self::Subnormal(n) => n,
+            Self::IntegerAndFractionalPart(int, fract) => int + fract,
+            Self::Nan(NanKind::Quiet) => f32::NAN,
+            Self::Nan(NanKind::Signaling) => f32::from_bits(SIGNALING_NAN_BITS),
+            Self::Infinity(Sign::Positive) => f32::INFINITY,
+            Self::Infinity(Sign::Negative) => f32::NEG_INFINITY,
+            Self::Zero(Sign::Positive) => 0.0,
+            Self::Zero(Sign::Negative) => -0.0,
+        }
+    }
+}
+
+impl Category for f32 {
+    type D = CatF32;
+    fn category(&self) -> Self::D {
+        if self.is_infinite() {
+            if self.is_sign_negative() {
+                return CatF32::Infinity(Sign::Negative);
+            }
+            return CatF32::Infinity(Sign::Positive);
+        }
+        if self.is_nan() {
+            if self.to_bits() & QUIET_NAN_BIT == 0 {
+                return CatF32::Nan(NanKind::Signaling);
+            }
+            return CatF32::Nan(NanKind::Quiet);
+        }
+        if *self == 0.0 {
+            if self.is_sign_negative() {
+                return CatF32::Zero(Sign::Negative);
+            }
+            return CatF32::Zero(Sign::Positive);
+        }
+        if self.is_subnormal() {
+            return CatF32::Subnormal(*self);
+        }
+
+        let int_part: f32 = FloatCore::trunc(*self);
+        let fract_part: f32 = FloatCore::fract(*self);
+
+        if fract_part == 0.0 {
+            CatF32::IntegerLike(int_part)
+        } else if int_part == 0.0 {
+            CatF32::FractionLike(fract_part)
+        } else {
+            CatF32::IntegerAndFractionalPart(int_part, fract_part)
+        }
+    }
+}
+
+/// Returns `true` if widening `x` to `f64` keeps its variant.
+///
+/// Widening is exact, so the parts never change, but the variant can: `f32` subnormals are
+/// normal `f64`s, and so become [`CatFloat::FractionLike`]. Only the variant is compared, not
+/// the payload or the [`NanKind`], since widening a signaling NaN may quiet it.
+///
+/// # Examples:
+/// ```rust
+/// # use floating_cat::*;
+/// assert!(classification_stable_on_widen(1.5));
+/// assert!(!classification_stable_on_widen(1e-40));
+/// ```
+pub fn classification_stable_on_widen(x: f32) -> bool {
+    matches!(
+        (x.category(), f64::from(x).category()),
+        (CatF32::IntegerLike(..), CatFloat::IntegerLike(..))
+            | (CatF32::FractionLike(..), CatFloat::FractionLike(..))
+            | (
+                CatF32::IntegerAndFractionalPart(..),
+                CatFloat::IntegerAndFractionalPart(..)
+            )
+            | (CatF32::Nan(..), CatFloat::Nan { .. })
+            | (CatF32::Infinity(..), CatFloat::Infinity(..))
+            | (CatF32::Zero(..), CatFloat::Zero(..))
+            | (CatF32::Subnormal(..), CatFloat::Subnormal(..))
+    )
+}
+
+#[test]
+fn cat_f32_keeps_exact_parts() {
+    let f: f32 = 0.1;
+    assert_eq!(f.category(), CatF32::FractionLike(0.1));
+    assert_ne!(f64::from(0.1f32), 0.1f64);
+
+    let f: f32 = 2.1;
+    assert_eq!(
+        f.category(),
+        CatF32::IntegerAndFractionalPart(2.0, 2.1 - 2.0)
+    );
+    assert_eq!(f.category().to_f32(), 2.1);
+}
+
+#[test]
+fn widening_stability_is_reported() {
+    for x in [1.0f32, 0.5, -2.75, 0.0, -0.0, f32::MAX, f32::MIN_POSITIVE] {
+        assert!(classification_stable_on_widen(x), "{x:?}");
+    }
+    for x in [f32::NAN, f32::INFINITY, f32::NEG_INFINITY] {
+        assert!(classification_stable_on_widen(x), "{x:?}");
+    }
+
+    // f32 subnormals are ordinary fractions once widened.
+    assert!(!classification_stable_on_widen(1e-40));
+    assert!(!classification_stable_on_widen(-f32::from_bits(1)));
+}
@@ -0,0 +1,101 @@
+//! A classification that borrows its payload from the classified value.
+
+use crate::{category_fast, CatFloat, NanKind, Sign};
+
+/// The borrowed counterpart of [`CatFloat`], from [`Category::category_ref`].
+///
+/// Variants whose payload is the classified value itself borrow it; the rest hold what
+/// classification computed, since there's nothing to borrow. For `f64` that's no cheaper
+/// than [`CatFloat`], but heavier value types can classify without copying themselves.
+///
+/// [`Category::category_ref`]: crate::Category::category_ref
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum CatFloatRef<'a> {
+    /// See [`CatFloat::IntegerLike`].
+    IntegerLike(&'a f64),
+
+    /// See [`CatFloat::FractionLike`].
+    FractionLike(&'a f64),
+
+    /// See [`CatFloat::IntegerAndFractionalPart`]; the parts are computed, not borrowed.
+    IntegerAndFractionalPart(f64, f64),
+
+    /// See [`CatFloat::Nan`].
+    Nan { kind: NanKind, payload: u64 },
+
+    /// See [`CatFloat::Infinity`].
+    Infinity(Sign),
+
+    /// See [`CatFloat::Zero`].
+    Zero(Sign),
+
+    /// See [`CatFloat::Subnormal`].
+    Subnormal(&'a f64),
+
+    /// See [`CatFloat::HugeInteger`].
+    HugeInteger(&'a f64),
+}
+
+impl<'a> CatFloatRef<'a> {
+    /// Classifies `x`, borrowing it where the variant's payload is `x` itself.
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use floating_cat::*;
+    /// let x = 0.5;
+    /// assert_eq!(CatFloatRef::of(&x), CatFloatRef::FractionLike(&x));
+    /// ```
+    pub fn of(x: &'a f64) -> Self {
+        match category_fast(*x) {
+            CatFloat::IntegerLike(..) => Self::IntegerLike(x),
+            CatFloat::FractionLike(..) => Self::FractionLike(x),
+            CatFloat::IntegerAndFractionalPart(int, fract) => {
+                Self::IntegerAndFractionalPart(int, fract)
+            }
+            CatFloat::Nan { kind, payload } => Self::Nan { kind, payload },
+            CatFloat::Infinity(sign) => Self::Infinity(sign),
+            CatFloat::Zero(sign) => Self::Zero(sign),
+            CatFloat::Subnormal(..) => Self::Subnormal(x),
+            CatFloat::HugeInteger(..) => Self::HugeInteger(x),
+        }
+    }
+
+    /// Copies the payload out into an owned [`CatFloat`].
+    pub fn to_owned(&self) -> CatFloat {
+        match *self {
+            Self::IntegerLike(n) => CatFloat::IntegerLike(*n),
+            Self::FractionLike(n) => CatFloat::FractionLike(*n),
+            Self::IntegerAndFractionalPart(int, fract) => {
+                CatFloat::IntegerAndFractionalPart(int, fract)
+            }
+            Self::Nan { kind, payload } => CatFloat::Nan { kind, payload },
+            Self::Infinity(sign) => CatFloat::Infinity(sign),
+            Self::Zero(sign) => CatFloat::Zero(sign),
+            Self::Subnormal(n) => CatFloat::Subnormal(*n),
+            Self::HugeInteger(n) => CatFloat::HugeInteger(*n),
+        }
+    }
+}
+
+impl From<CatFloatRef<'_>> for CatFloat {
+    fn from(cat: CatFloatRef<'_>) -> Self {
+        cat.to_owned()
+    }
+}
+
+#[test]
+fn category_ref_borrows_the_value() {
+    use crate::Category;
+
+    let x = 3.0;
+    match x.category_ref() {
+        CatFloatRef::IntegerLike(n) => assert!(core::ptr::eq(n, &x)),
+        other => panic!("{other:?}"),
+    }
+
+    for x in [3.0, 0.5, -1.25, 5e-324, 1e40, -0.0, f64::NAN, f64::INFINITY] {
+        let owned = CatFloat::from(x.category_ref());
+        assert_eq!(owned.to_f64().to_bits(), x.category().to_f64().to_bits());
+        assert_eq!(owned.variant_index(), x.category().variant_index());
+    }
+}
@@ -0,0 +1,100 @@
+//! A configurable classifier, for callers that want special values handled differently.
+
+use crate::{CatFloat, Category, Sign};
+
+/// Classifies floats like [`Category::category`], with options for NaN and subnormals.
+///
+/// `Classifier::new()` reproduces [`Category::category`] exactly; each option then changes
+/// how one kind of value is reported.
+///
+/// # Examples:
+/// ```rust
+/// # use floating_cat::*;
+/// let classifier = Classifier::new().treat_subnormal_as_zero(true);
+/// assert_eq!(classifier.classify(5e-324), CatFloat::Zero(Sign::Positive));
+/// assert_eq!(classifier.classify(1.5), 1.5f64.category());
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Copy, Clone)]
+pub struct Classifier {
+    nan_as_fraction: bool,
+    treat_subnormal_as_zero: bool,
+}
+
+impl Classifier {
+    /// A classifier that behaves exactly like [`Category::category`].
+    pub const fn new() -> Self {
+        Self {
+            nan_as_fraction: false,
+            treat_subnormal_as_zero: false,
+        }
+    }
+
+    /// Reports NaN as `FractionLike(0.0)`, an empty fraction, instead of [`CatFloat::Nan`].
+    ///
+    /// The payload is zero rather than NaN so the result still equals itself and rebuilds to
+    /// a number; the NaN's sign, kind and payload are dropped.
+    pub const fn nan_as_fraction(mut self, yes: bool) -> Self {
+        self.nan_as_fraction = yes;
+        self
+    }
+
+    /// Reports subnormals as [`CatFloat::Zero`] of the same sign, flushing them like an FPU's
+    /// denormals-are-zero mode.
+    pub const fn treat_subnormal_as_zero(mut self, yes: bool) -> Self {
+        self.treat_subnormal_as_zero = yes;
+        self
+    }
+
+    /// Classifies `x` with this classifier's options.
+    pub fn classify(&self, x: f64) -> CatFloat {
+        match x.category() {
+            CatFloat::Nan { .. } if self.nan_as_fraction => CatFloat::FractionLike(0.0),
+            CatFloat::Subnormal(n) if self.treat_subnormal_as_zero => {
+                if n.is_sign_negative() {
+                    CatFloat::Zero(Sign::Negative)
+                } else {
+                    CatFloat::Zero(Sign::Positive)
+                }
+            }
+            cat => cat,
+        }
+    }
+}
+
+#[test]
+fn default_classifier_matches_category() {
+    let classifier = Classifier::default();
+    assert_eq!(classifier, Classifier::new());
+
+    for x in [1.0, 0.5, -1.5, 0.0, 5e-324, 1e300, f64::INFINITY] {
+        assert_eq!(classifier.classify(x), x.category());
+    }
+    assert!(classifier.classify(f64::NAN).is_nan());
+}
+
+#[test]
+fn classifier_options_combine() {
+    let flush = Classifier::new().treat_subnormal_as_zero(true);
+    assert_eq!(flush.classify(-5e-324), CatFloat::Zero(Sign::Negative));
+    assert!(flush.classify(f64::NAN).is_nan());
+
+    let both = flush.nan_as_fraction(true);
+    assert_eq!(both.classify(5e-324), CatFloat::Zero(Sign::Positive));
+    assert_eq!(both.classify(f64::NAN), CatFloat::FractionLike(0.0));
+    assert_eq!(both.classify(0.5), CatFloat::FractionLike(0.5));
+}
+
+#[test]
+fn nan_as_fraction_is_well_defined() {
+    use std::collections::HashSet;
+
+    let classifier = Classifier::new().nan_as_fraction(true);
+    let c = classifier.classify(f64::NAN);
+    assert_eq!(c, c);
+    assert_eq!(c.to_f64(), 0.0);
+    assert_eq!(classifier.classify(-f64::NAN), c);
+
+    let signaling = f64::from_bits(0x7ff0_0000_0000_0001);
+    let set: HashSet<CatFloat> = [c, c, classifier.classify(signaling)].into_iter().collect();
+    assert_eq!(set.len(), 1);
+}
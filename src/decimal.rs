@@ -0,0 +1,93 @@
+//! Classifying `rust_decimal` decimals, behind the `decimal` feature.
+
+use rust_decimal::Decimal;
+
+use crate::Category;
+
+/// The category of a [`Decimal`].
+///
+/// Decimals are split exactly, without going through `f64`. They can't be NaN or infinite,
+/// so only the finite splits exist, and zero is simply [`IntegerLike`].
+///
+/// [`IntegerLike`]: CatDecimal::IntegerLike
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub enum CatDecimal {
+    /// For decimals like `4` or `4.00`, with no fractional part.
+    IntegerLike(Decimal),
+
+    /// For decimals like `0.5` or `-0.25`, where there's no integer part.
+    FractionLike(Decimal),
+
+    /// The Integer and Fractional parts of a decimal, in that order.
+    /// Both share the decimal's sign, as with [`CatFloat`](crate::CatFloat).
+    IntegerAndFractionalPart(Decimal, Decimal),
+}
+
+impl CatDecimal {
+    /// Returns `true` if the Classified decimal is [`IntegerLike`].
+    ///
+    /// [`IntegerLike`]: CatDecimal::IntegerLike
+    #[must_use]
+    pub fn is_integer_like(&self) -> bool {
+        matches!(self, Self::IntegerLike(..))
+    }
+
+    /// Returns `true` if the Classified decimal is [`FractionLike`].
+    ///
+    /// [`FractionLike`]: CatDecimal::FractionLike
+    #[must_use]
+    pub fn is_fraction_like(&self) -> bool {
+        matches!(self, Self::FractionLike(..))
+    }
+
+    /// Returns `true` if the Classified decimal is [`IntegerAndFractionalPart`].
+    ///
+    /// [`IntegerAndFractionalPart`]: CatDecimal::IntegerAndFractionalPart
+    #[must_use]
+    pub fn is_integer_and_fractional_part(&self) -> bool {
+        matches!(self, Self::IntegerAndFractionalPart(..))
+    }
+}
+
+impl Category for Decimal {
+    type D = CatDecimal;
+    fn category(&self) -> Self::D {
+        let int_part: Decimal = self.trunc();
+        let fract_part: Decimal = self.fract();
+
+        if fract_part.is_zero() {
+            CatDecimal::IntegerLike(int_part)
+        } else if int_part.is_zero() {
+            CatDecimal::FractionLike(fract_part)
+        } else {
+            CatDecimal::IntegerAndFractionalPart(int_part, fract_part)
+        }
+    }
+}
+
+#[test]
+fn decimals_are_classified() {
+    assert_eq!(
+        Decimal::new(15, 1).category(),
+        CatDecimal::IntegerAndFractionalPart(Decimal::ONE, Decimal::new(5, 1))
+    );
+    assert_eq!(
+        Decimal::new(400, 2).category(),
+        CatDecimal::IntegerLike(Decimal::new(4, 0))
+    );
+    assert_eq!(
+        Decimal::new(-25, 2).category(),
+        CatDecimal::FractionLike(Decimal::new(-25, 2))
+    );
+    assert_eq!(
+        Decimal::new(-35, 1).category(),
+        CatDecimal::IntegerAndFractionalPart(Decimal::new(-3, 0), Decimal::new(-5, 1))
+    );
+    assert!(Decimal::ZERO.category().is_integer_like());
+
+    // 0.1 is exact as a decimal, unlike as an f64.
+    assert_eq!(
+        Decimal::new(1, 1).category(),
+        CatDecimal::FractionLike(Decimal::new(1, 1))
+    );
+}
@@ -0,0 +1,103 @@
+//! The raw IEEE-754 layout of an `f64`.
+
+/// Number of explicitly stored mantissa bits.
+pub(crate) const MANTISSA_BITS: u32 = 52;
+/// Added to the real exponent before it's stored.
+pub(crate) const EXPONENT_BIAS: i32 = 1023;
+
+pub(crate) const SIGN_MASK: u64 = 1 << 63;
+pub(crate) const EXPONENT_MASK: u64 = 0x7ff;
+pub(crate) const MANTISSA_MASK: u64 = (1 << MANTISSA_BITS) - 1;
+/// The top mantissa bit, which is set for quiet NaNs.
+pub(crate) const QUIET_NAN_BIT: u64 = 1 << (MANTISSA_BITS - 1);
+/// The mantissa bits below the quiet bit, which NaNs can use to carry a payload.
+pub(crate) const NAN_PAYLOAD_MASK: u64 = QUIET_NAN_BIT - 1;
+
+/// Splits an `f64` into its sign bit, 11-bit biased exponent, and 52-bit mantissa.
+///
+/// # Examples:
+/// ```rust
+/// # use floating_cat::*;
+/// assert_eq!(decompose_ieee754(1.5), (false, 1023, 1 << 51));
+/// assert_eq!(decompose_ieee754(-0.0), (true, 0, 0));
+/// ```
+pub const fn decompose_ieee754(x: f64) -> (bool, u16, u64) {
+    let bits = x.to_bits();
+    let sign = bits & SIGN_MASK != 0;
+    let exponent = ((bits >> MANTISSA_BITS) & EXPONENT_MASK) as u16;
+    let mantissa = bits & MANTISSA_MASK;
+    (sign, exponent, mantissa)
+}
+
+/// Builds an `f64` from a sign bit, biased exponent, and mantissa; the inverse of [`decompose_ieee754`].
+///
+/// Bits outside the 11-bit exponent and 52-bit mantissa are ignored.
+pub const fn recompose_ieee754(sign: bool, exponent: u16, mantissa: u64) -> f64 {
+    let sign = if sign { SIGN_MASK } else { 0 };
+    let exponent = (exponent as u64 & EXPONENT_MASK) << MANTISSA_BITS;
+    f64::from_bits(sign | exponent | (mantissa & MANTISSA_MASK))
+}
+
+/// `2^exponent`, for exponents in the normal range `-1022..=1023`.
+const fn pow2(exponent: i32) -> f64 {
+    recompose_ieee754(false, (exponent + EXPONENT_BIAS) as u16, 0)
+}
+
+/// Multiplies `x` by `2^k` by adjusting its exponent, like C's `scalbn`.
+///
+/// Nothing is rounded unless the result overflows to infinity or becomes subnormal.
+pub(crate) fn scale_pow2(mut x: f64, mut k: i32) -> f64 {
+    const MAX: i32 = 1023;
+    const MIN: i32 = -1022;
+
+    // Take the scale in steps, so each power of two stays representable.
+    // Stepping down also adds 53 to keep the mantissa's bits above the subnormal range.
+    for _ in 0..2 {
+        if k > MAX {
+            x *= pow2(MAX);
+            k -= MAX;
+        } else if k < MIN {
+            x *= pow2(MIN) * pow2(MANTISSA_BITS as i32 + 1);
+            k -= MIN + MANTISSA_BITS as i32 + 1;
+        }
+    }
+    x * pow2(k.clamp(MIN, MAX))
+}
+
+#[test]
+fn ieee754_round_trips() {
+    for x in [
+        1.5,
+        -0.0,
+        5e-324,
+        f64::MIN_POSITIVE / 3.0,
+        f64::MAX,
+        f64::NEG_INFINITY,
+    ] {
+        let (sign, exponent, mantissa) = decompose_ieee754(x);
+        assert_eq!(
+            recompose_ieee754(sign, exponent, mantissa).to_bits(),
+            x.to_bits()
+        );
+    }
+
+    assert_eq!(decompose_ieee754(1.5), (false, 1023, 1 << 51));
+    assert_eq!(decompose_ieee754(-0.0), (true, 0, 0));
+    assert_eq!(decompose_ieee754(5e-324), (false, 0, 1));
+}
+
+#[test]
+fn scale_pow2_is_exact() {
+    assert_eq!(scale_pow2(0.25, 2), 1.0);
+    assert_eq!(scale_pow2(3.0, -1), 1.5);
+    assert_eq!(scale_pow2(5e-324, 1074), 1.0);
+    assert_eq!(scale_pow2(1.0, -1074), 5e-324);
+    assert_eq!(
+        scale_pow2(f64::MAX, -2046),
+        f64::MAX / 2f64.powi(1023) / 2f64.powi(1023)
+    );
+    assert_eq!(scale_pow2(1.0, 1024), f64::INFINITY);
+    assert_eq!(scale_pow2(1.0, -1100), 0.0);
+    assert_eq!(scale_pow2(-1.5, 0), -1.5);
+    assert!(scale_pow2(f64::NAN, 3).is_nan());
+}
@@ -0,0 +1,66 @@
+//! Lazily classifying iterators of floats.
+
+use crate::{CatFloat, Category};
+
+/// Adds [`categorize`] to every iterator over `f64`s.
+///
+/// [`categorize`]: CategorizeExt::categorize
+pub trait CategorizeExt: Iterator<Item = f64> + Sized {
+    /// Classifies each float as it's yielded.
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use floating_cat::*;
+    /// let mut cats = [1.0, 0.5].into_iter().categorize();
+    /// assert_eq!(cats.next(), Some(CatFloat::IntegerLike(1.0)));
+    /// assert_eq!(cats.next(), Some(CatFloat::FractionLike(0.5)));
+    /// assert_eq!(cats.next(), None);
+    /// ```
+    fn categorize(self) -> Categorize<Self> {
+        Categorize { iter: self }
+    }
+}
+
+impl<I: Iterator<Item = f64>> CategorizeExt for I {}
+
+/// An iterator that classifies the floats of another iterator.
+///
+/// Created by [`CategorizeExt::categorize`].
+#[derive(Debug, Clone)]
+pub struct Categorize<I> {
+    iter: I,
+}
+
+impl<I: Iterator<Item = f64>> Iterator for Categorize<I> {
+    type Item = CatFloat;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|x| x.category())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: DoubleEndedIterator<Item = f64>> DoubleEndedIterator for Categorize<I> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|x| x.category())
+    }
+}
+
+impl<I: ExactSizeIterator<Item = f64>> ExactSizeIterator for Categorize<I> {}
+
+#[test]
+fn categorize_matches_manual() {
+    use crate::*;
+
+    let xs = [1.0, 0.5, 1.5];
+    let mut cats = xs.into_iter().categorize();
+    assert_eq!(cats.size_hint(), (3, Some(3)));
+
+    for x in xs {
+        assert_eq!(cats.next(), Some(x.category()));
+    }
+    assert_eq!(cats.next(), None);
+}
@@ -1,4 +1,48 @@
-use num::traits::Zero;
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(all(test, not(feature = "std")))]
+extern crate std;
+
+pub mod batch;
+mod cached;
+#[cfg(feature = "half")]
+mod cat_f16;
+mod cat_f32;
+mod cat_ref;
+mod classifier;
+#[cfg(feature = "decimal")]
+mod decimal;
+pub mod ieee754;
+pub mod iter;
+mod non_nan;
+#[cfg(feature = "rational")]
+mod rational;
+#[cfg(feature = "proptest")]
+pub mod strategy;
+mod visit;
+
+pub use cached::CachedCategory;
+#[cfg(feature = "half")]
+pub use cat_f16::CatF16;
+pub use cat_f32::{classification_stable_on_widen, CatF32};
+pub use cat_ref::CatFloatRef;
+pub use classifier::Classifier;
+#[cfg(feature = "decimal")]
+pub use decimal::CatDecimal;
+pub use ieee754::{decompose_ieee754, recompose_ieee754};
+pub use iter::{Categorize, CategorizeExt};
+pub use non_nan::NonNan;
+#[cfg(feature = "rational")]
+pub use rational::CatRational;
+pub use visit::CatFloatVisitor;
+
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use core::num::{FpCategory, ParseFloatError};
+use num::traits::float::FloatCore;
 
 pub trait Category {
     /// Type returned from `destructure`.
@@ -12,34 +56,160 @@ pub trait Category {
     /// let n: f64 = 1.5;
     /// assert_eq!(n.category(), CatFloat::IntegerAndFractionalPart(1.0, 0.5));
     /// ```
+    #[must_use]
     fn category(&self) -> Self::D;
+
+    /// Classifies `self` into `out`, for callers reusing one output across many calls.
+    ///
+    /// The default just assigns [`category`](Category::category)'s result; impls with
+    /// allocating outputs can override it to reuse `out`'s storage.
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use floating_cat::*;
+    /// let mut out = CatFloat::default();
+    /// 1.5f64.classify_into(&mut out);
+    /// assert_eq!(out, CatFloat::IntegerAndFractionalPart(1.0, 0.5));
+    /// ```
+    fn classify_into(&self, out: &mut Self::D) {
+        *out = self.category();
+    }
+
+    /// Classifies `self` without copying it where the variant's payload is the value itself;
+    /// see [`CatFloatRef`].
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use floating_cat::*;
+    /// let x = 0.5f64;
+    /// assert_eq!(x.category_ref(), CatFloatRef::FractionLike(&x));
+    /// ```
+    fn category_ref(&self) -> CatFloatRef<'_>
+    where
+        Self: Borrow<f64>,
+    {
+        CatFloatRef::of(Borrow::<f64>::borrow(self))
+    }
 }
 
-#[derive(Debug, PartialEq, Copy, Clone)]
+/// Classifies each `f64` argument, returning a `[CatFloat; N]` in argument order.
+///
+/// Arguments can be any expressions that evaluate to `f64`.
+///
+/// # Examples:
+/// ```rust
+/// # use floating_cat::*;
+/// let x = 0.25;
+/// let cats = classify_all!(1.0, x * 2.0, 1.5);
+/// assert_eq!(
+///     cats,
+///     [
+///         CatFloat::IntegerLike(1.0),
+///         CatFloat::FractionLike(0.5),
+///         CatFloat::IntegerAndFractionalPart(1.0, 0.5),
+///     ]
+/// );
+/// ```
+#[macro_export]
+macro_rules! classify_all {
+    ($($x:expr),* $(,)?) => {
+        [$({
+            let x: f64 = $x;
+            $crate::Category::category(&x)
+        }),*]
+    };
+}
+
+/// The sign of a classified float.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Sign {
+    Positive,
+    Negative,
+}
+
+/// Whether a NaN is quiet or signaling, going by the top bit of its mantissa.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NanKind {
+    /// The top mantissa bit is set. Arithmetic produces these.
+    Quiet,
+
+    /// The top mantissa bit is clear. Some FPUs trap when these are used.
+    Signaling,
+}
+
+/// The non-finite value a classification stands for, as returned by [`CatFloat::parts`].
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Special {
+    Nan,
+    Infinity,
+}
+
+/// An arithmetic operation for [`CatFloat::combine`].
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub enum ArithOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+/// The category of a float, as returned by [`Category::category`].
+///
+/// `NaN` and `Infinity` carry no float payload, so with the `serde` feature
+/// they serialize without floats (`{"Nan":{"kind":"Quiet","payload":0}}`,
+/// `{"Infinity":"Positive"}`) and stay representable in formats like JSON.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CatFloat {
     /// For `f64`s like `1.0`, `-100.0`, with no fractional part.
     /// Being integer-like means this can usually be casted as an integer without issue.
-    /// Note: `f64::MAX > u128::MAX`
+    /// Note: `f64::MAX > u128::MAX`, so integers too big for any integer type are [`HugeInteger`] instead.
+    ///
+    /// [`HugeInteger`]: CatFloat::HugeInteger
     IntegerLike(f64),
 
     /// For `f64`s like `0.5` or `-0.002`, where there's no integer part.
     /// Casting this as an integer wouldn't be recommended, since you'd lose information.
+    /// The payload keeps the input's sign, so it's in `-1.0..1.0`, not just `0.0..1.0`.
     FractionLike(f64),
 
     /// The Integer and Fractional parts of an f64, in that order.
     IntegerAndFractionalPart(f64, f64),
 
-    /// The Float was NaN
-    Nan,
+    /// The Float was NaN, either quiet or signaling.
+    ///
+    /// `payload` holds the mantissa bits below the quiet bit: the low 51 bits of
+    /// `to_bits()`. An `f64::NAN` has a payload of `0`.
+    Nan { kind: NanKind, payload: u64 },
 
-    /// The Float was Infinity
-    Infinity,
+    /// The Float was Infinity, either positive or negative.
+    Infinity(Sign),
+
+    /// The Float was exactly zero, either `0.0` or `-0.0`.
+    Zero(Sign),
+
+    /// For subnormal (denormal) floats like `5e-324`, which are too small to be normal.
+    /// Some hardware flushes these to zero.
+    Subnormal(f64),
+
+    /// For integer-valued `f64`s like `1e300`, whose magnitude is at least `u128::MAX as f64`.
+    /// These can't be casted to any integer type without saturating.
+    HugeInteger(f64),
 }
 
 impl CatFloat {
+    /// A placeholder classification for initialising storage: the classification of `0.0`.
+    ///
+    /// This is the same as [`CatFloat::default`].
+    pub const EMPTY: CatFloat = CatFloat::Zero(Sign::Positive);
+
     /// Returns `true` if the Classified float is [`IntegerLike`].
     ///
     /// [`IntegerLike`]: CatFloat::IntegerLike    
+    #[must_use]
     pub fn is_integer_like(&self) -> bool {
         matches!(self, Self::IntegerLike(..))
     }
@@ -47,6 +217,7 @@ impl CatFloat {
     /// Returns `true` if the Classified float is [`FractionLike`].
     ///
     /// [`FractionLike`]: CatFloat::FractionLike    
+    #[must_use]
     pub fn is_fraction_like(&self) -> bool {
         matches!(self, Self::FractionLike(..))
     }
@@ -54,6 +225,7 @@ impl CatFloat {
     /// Returns `true` if the Classified float is [`IntegerAndFractionalPart`].
     ///
     /// [`IntegerAndFractionalPart`]: CatFloat::IntegerAndFractionalPart    
+    #[must_use]
     pub fn is_integer_and_fractional_part(&self) -> bool {
         matches!(self, Self::IntegerAndFractionalPart(..))
     }
@@ -61,57 +233,4170 @@ impl CatFloat {
     /// Returns `true` if the Classified float is [`Infinity`].
     ///
     /// [`Infinity`]: CatFloat::Infinity    
+    #[must_use]
     pub fn is_infinity(&self) -> bool {
-        matches!(self, Self::Infinity)
+        matches!(self, Self::Infinity(..))
+    }
+
+    /// Returns `true` if the Classified float is positive [`Infinity`].
+    ///
+    /// [`Infinity`]: CatFloat::Infinity
+    #[must_use]
+    pub fn is_positive_infinity(&self) -> bool {
+        matches!(self, Self::Infinity(Sign::Positive))
+    }
+
+    /// Returns `true` if the Classified float is negative [`Infinity`].
+    ///
+    /// [`Infinity`]: CatFloat::Infinity
+    #[must_use]
+    pub fn is_negative_infinity(&self) -> bool {
+        matches!(self, Self::Infinity(Sign::Negative))
+    }
+
+    /// Returns `true` if the Classified float is [`Zero`], of either sign.
+    ///
+    /// [`Zero`]: CatFloat::Zero
+    #[must_use]
+    pub fn is_zero(&self) -> bool {
+        matches!(self, Self::Zero(..))
+    }
+
+    /// Returns `true` if the Classified float is negative [`Zero`].
+    ///
+    /// [`Zero`]: CatFloat::Zero
+    #[must_use]
+    pub fn is_negative_zero(&self) -> bool {
+        matches!(self, Self::Zero(Sign::Negative))
+    }
+
+    /// Returns `true` if the Classified float is [`Subnormal`].
+    ///
+    /// [`Subnormal`]: CatFloat::Subnormal
+    #[must_use]
+    pub fn is_subnormal(&self) -> bool {
+        matches!(self, Self::Subnormal(..))
+    }
+
+    /// Returns `true` if the Classified float is [`HugeInteger`].
+    ///
+    /// [`HugeInteger`]: CatFloat::HugeInteger
+    #[must_use]
+    pub fn is_huge_integer(&self) -> bool {
+        matches!(self, Self::HugeInteger(..))
     }
 
     /// Returns `true` if the Classified float is [`Nan`].
     ///
     /// [`Nan`]: CatFloat::Nan    
+    #[must_use]
     pub fn is_nan(&self) -> bool {
-        matches!(self, Self::Nan)
+        matches!(self, Self::Nan { .. })
     }
-}
 
-impl Category for f64 {
-    type D = CatFloat;
-    fn category(&self) -> Self::D {
-        if self.is_infinite() {
-            return CatFloat::Infinity;
+    /// Returns `true` if the Classified float is a signaling [`Nan`].
+    ///
+    /// [`Nan`]: CatFloat::Nan
+    #[must_use]
+    pub fn is_signaling_nan(&self) -> bool {
+        matches!(
+            self,
+            Self::Nan {
+                kind: NanKind::Signaling,
+                ..
+            }
+        )
+    }
+
+    /// Rebuilds the `f64` that was classified.
+    ///
+    /// For every finite, non-NaN `x`, `x.category().to_f64() == x`.
+    /// NaNs keep their kind and payload, but not their sign bit.
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use floating_cat::*;
+    /// let n: f64 = -1.5;
+    /// assert_eq!(n.category().to_f64(), n);
+    /// ```
+    pub fn to_f64(&self) -> f64 {
+        match *self {
+            Self::IntegerLike(n)
+            | Self::FractionLike(n)
+            | Self::Subnormal(n)
+            | Self::HugeInteger(n) => n,
+            Self::IntegerAndFractionalPart(int, fract) => int + fract,
+            Self::Nan { kind, payload } => {
+                let payload = payload & ieee754::NAN_PAYLOAD_MASK;
+                match kind {
+                    NanKind::Quiet => f64::from_bits(f64::NAN.to_bits() | payload),
+                    // A signaling NaN needs a nonzero payload, or it would be infinity.
+                    NanKind::Signaling if payload == 0 => f64::from_bits(SIGNALING_NAN_BITS),
+                    NanKind::Signaling => f64::from_bits(f64::INFINITY.to_bits() | payload),
+                }
+            }
+            Self::Infinity(Sign::Positive) => f64::INFINITY,
+            Self::Infinity(Sign::Negative) => f64::NEG_INFINITY,
+            Self::Zero(Sign::Positive) => 0.0,
+            Self::Zero(Sign::Negative) => -0.0,
+        }
+    }
+
+    /// Returns the sign of the float that was classified, or `None` for [`Nan`].
+    ///
+    /// Both parts of an [`IntegerAndFractionalPart`] share the original's sign,
+    /// so `-1.5` reports [`Sign::Negative`].
+    ///
+    /// [`Nan`]: CatFloat::Nan
+    /// [`IntegerAndFractionalPart`]: CatFloat::IntegerAndFractionalPart
+    pub fn sign(&self) -> Option<Sign> {
+        let sign_of = |n: f64| {
+            if n.is_sign_negative() {
+                Sign::Negative
+            } else {
+                Sign::Positive
+            }
+        };
+
+        match *self {
+            Self::IntegerLike(n)
+            | Self::FractionLike(n)
+            | Self::Subnormal(n)
+            | Self::HugeInteger(n)
+            | Self::IntegerAndFractionalPart(n, _) => Some(sign_of(n)),
+            Self::Infinity(sign) | Self::Zero(sign) => Some(sign),
+            Self::Nan { .. } => None,
         }
-        if self.is_nan() {
-            return CatFloat::Nan;
+    }
+
+    /// Maps the classification onto the standard library's [`FpCategory`].
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use floating_cat::*;
+    /// let n: f64 = 1.5;
+    /// assert_eq!(n.category().fp_category(), n.classify());
+    /// ```
+    pub fn fp_category(&self) -> FpCategory {
+        match self {
+            Self::IntegerLike(..)
+            | Self::FractionLike(..)
+            | Self::IntegerAndFractionalPart(..)
+            | Self::HugeInteger(..) => FpCategory::Normal,
+            Self::Nan { .. } => FpCategory::Nan,
+            Self::Infinity(..) => FpCategory::Infinite,
+            Self::Zero(..) => FpCategory::Zero,
+            Self::Subnormal(..) => FpCategory::Subnormal,
         }
+    }
 
-        let int_part: f64 = self.trunc();
-        let fract_part: f64 = self.fract();
+    /// Converts an integer-valued classification into an `i64`.
+    ///
+    /// Values with a fractional part are rejected rather than rounded.
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use floating_cat::*;
+    /// assert_eq!(3.0f64.category().try_as_i64(), Ok(3));
+    /// assert_eq!(1.5f64.category().try_as_i64(), Err(CastError::NotInteger));
+    /// ```
+    pub fn try_as_i64(&self) -> Result<i64, CastError> {
+        // `i64::MIN` is exactly representable as an `f64`, but `i64::MAX` is not:
+        // `i64::MAX as f64` rounds up to 2^63, which is already out of range.
+        const LOWER: f64 = i64::MIN as f64;
+        const UPPER: f64 = -(i64::MIN as f64);
 
-        if fract_part.is_zero() {
-            CatFloat::IntegerLike(int_part)
-        } else if int_part.is_zero() {
-            CatFloat::FractionLike(fract_part)
-        } else {
-            CatFloat::IntegerAndFractionalPart(int_part, fract_part)
+        match *self {
+            Self::IntegerLike(n) if (LOWER..UPPER).contains(&n) => Ok(n as i64),
+            Self::IntegerLike(..) | Self::HugeInteger(..) => Err(CastError::OutOfRange),
+            Self::Zero(..) => Ok(0),
+            Self::FractionLike(..) | Self::IntegerAndFractionalPart(..) | Self::Subnormal(..) => {
+                Err(CastError::NotInteger)
+            }
+            Self::Nan { .. } => Err(CastError::WasNaN),
+            Self::Infinity(..) => Err(CastError::WasInfinite),
         }
     }
-}
 
-#[test]
-fn trait_works() {
-    use crate::*;
+    /// Orders classifications by the value they were built from, using [`f64::total_cmp`].
+    ///
+    /// [`Nan`] sorts after everything else, and infinities sit at the extremes.
+    /// `CatFloat` doesn't implement `Ord`; pass this to `sort_by` instead.
+    ///
+    /// [`Nan`]: CatFloat::Nan
+    pub fn total_cmp(&self, other: &CatFloat) -> Ordering {
+        match (self, other) {
+            (Self::Nan { .. }, Self::Nan { .. }) => Ordering::Equal,
+            (Self::Nan { .. }, _) => Ordering::Greater,
+            (_, Self::Nan { .. }) => Ordering::Less,
+            _ => self.to_f64().total_cmp(&other.to_f64()),
+        }
+    }
 
-    let f: f64 = 1.5;
-    assert_eq!(f.category(), CatFloat::IntegerAndFractionalPart(1.0, 0.5));
+    /// Applies `f` to each stored payload, keeping the variant as-is.
+    ///
+    /// The result isn't re-categorized, so it may no longer describe a valid split:
+    /// doubling `FractionLike(0.5)` gives `FractionLike(1.0)`.
+    /// Call `.to_f64().category()` on the result to re-categorize it.
+    /// [`Nan`], [`Infinity`] and [`Zero`] have no payload and are returned unchanged.
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use floating_cat::*;
+    /// let doubled = CatFloat::IntegerAndFractionalPart(1.0, 0.5).map_parts(|n| n * 2.0);
+    /// assert_eq!(doubled, CatFloat::IntegerAndFractionalPart(2.0, 1.0));
+    /// ```
+    ///
+    /// [`Nan`]: CatFloat::Nan
+    /// [`Infinity`]: CatFloat::Infinity
+    /// [`Zero`]: CatFloat::Zero
+    pub fn map_parts<F: Fn(f64) -> f64>(self, f: F) -> CatFloat {
+        match self {
+            Self::IntegerLike(n) => Self::IntegerLike(f(n)),
+            Self::FractionLike(n) => Self::FractionLike(f(n)),
+            Self::IntegerAndFractionalPart(int, fract) => {
+                Self::IntegerAndFractionalPart(f(int), f(fract))
+            }
+            Self::Subnormal(n) => Self::Subnormal(f(n)),
+            Self::HugeInteger(n) => Self::HugeInteger(f(n)),
+            Self::Nan { .. } | Self::Infinity(..) | Self::Zero(..) => self,
+        }
+    }
 
-    let f: f64 = 1.0;
-    assert_eq!(f.category(), CatFloat::IntegerLike(1.0));
+    /// Returns the integer part of the classified float, like `f64::trunc`, or `None` for
+    /// [`Nan`] and [`Infinity`].
+    ///
+    /// Values without an integer part, like [`FractionLike`], give a zero with their sign.
+    ///
+    /// [`Nan`]: CatFloat::Nan
+    /// [`Infinity`]: CatFloat::Infinity
+    /// [`FractionLike`]: CatFloat::FractionLike
+    pub fn integer_part(&self) -> Option<f64> {
+        match *self {
+            Self::IntegerLike(n) | Self::HugeInteger(n) | Self::IntegerAndFractionalPart(n, _) => {
+                Some(n)
+            }
+            Self::FractionLike(n) | Self::Subnormal(n) => Some(0.0f64.copysign(n)),
+            Self::Zero(Sign::Positive) => Some(0.0),
+            Self::Zero(Sign::Negative) => Some(-0.0),
+            Self::Nan { .. } | Self::Infinity(..) => None,
+        }
+    }
 
-    let f: f64 = 0.2;
-    assert_eq!(f.category(), CatFloat::FractionLike(0.2));
+    /// Returns the fractional part of the classified float, like `f64::fract`, or `None` for
+    /// [`Nan`] and [`Infinity`].
+    ///
+    /// Values without a fractional part, like [`IntegerLike`], give `0.0`.
+    ///
+    /// [`Nan`]: CatFloat::Nan
+    /// [`Infinity`]: CatFloat::Infinity
+    /// [`IntegerLike`]: CatFloat::IntegerLike
+    pub fn fractional_part(&self) -> Option<f64> {
+        match *self {
+            Self::FractionLike(n) | Self::Subnormal(n) | Self::IntegerAndFractionalPart(_, n) => {
+                Some(n)
+            }
+            Self::IntegerLike(..) | Self::HugeInteger(..) | Self::Zero(..) => Some(0.0),
+            Self::Nan { .. } | Self::Infinity(..) => None,
+        }
+    }
 
-    let f: f64 = f64::INFINITY;
-    assert_eq!(f.category(), CatFloat::Infinity,);
+    /// Returns `true` if both classifications are the same variant and every `f64` payload
+    /// differs by at most `eps`.
+    ///
+    /// Variants are compared structurally, so [`IntegerLike`]`(1.0)` is never approximately
+    /// equal to [`FractionLike`]`(1.0)`, and a [`Nan`] is only approximately equal to a [`Nan`]
+    /// of the same [`NanKind`]. Use `==` for exact comparison.
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use floating_cat::*;
+    /// let a = CatFloat::IntegerAndFractionalPart(1.0, 0.5000001);
+    /// assert!(a.approx_eq(&CatFloat::IntegerAndFractionalPart(1.0, 0.5), 1e-3));
+    /// ```
+    ///
+    /// [`IntegerLike`]: CatFloat::IntegerLike
+    /// [`FractionLike`]: CatFloat::FractionLike
+    /// [`Nan`]: CatFloat::Nan
+    pub fn approx_eq(&self, other: &CatFloat, eps: f64) -> bool {
+        let close = |a: f64, b: f64| (a - b).abs() <= eps;
+        match (*self, *other) {
+            (Self::IntegerLike(a), Self::IntegerLike(b))
+            | (Self::FractionLike(a), Self::FractionLike(b))
+            | (Self::Subnormal(a), Self::Subnormal(b))
+            | (Self::HugeInteger(a), Self::HugeInteger(b)) => close(a, b),
+            (Self::IntegerAndFractionalPart(ai, af), Self::IntegerAndFractionalPart(bi, bf)) => {
+                close(ai, bi) && close(af, bf)
+            }
+            (Self::Nan { kind: a, .. }, Self::Nan { kind: b, .. }) => a == b,
+            (Self::Infinity(a), Self::Infinity(b)) | (Self::Zero(a), Self::Zero(b)) => a == b,
+            _ => false,
+        }
+    }
 
-    let f: f64 = f64::NAN;
-    assert_eq!(f.category(), CatFloat::Nan);
+    /// Returns `true` unless the Classified float is [`Nan`] or [`Infinity`].
+    ///
+    /// [`Nan`]: CatFloat::Nan
+    /// [`Infinity`]: CatFloat::Infinity
+    #[must_use]
+    pub fn is_finite(&self) -> bool {
+        !matches!(self, Self::Nan { .. } | Self::Infinity(..))
+    }
+
+    /// Returns `true` if the Classified float is a whole number that fits in an `i128`.
+    ///
+    /// That is an [`IntegerLike`] in `i128::MIN..=i128::MAX`, or a [`Zero`].
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use floating_cat::*;
+    /// assert!(1.0f64.category().is_representable_as_integer());
+    /// assert!(!1e300f64.category().is_representable_as_integer());
+    /// ```
+    ///
+    /// [`IntegerLike`]: CatFloat::IntegerLike
+    /// [`Zero`]: CatFloat::Zero
+    #[must_use]
+    pub fn is_representable_as_integer(&self) -> bool {
+        // i128::MIN is -2^127 exactly; i128::MAX rounds up to 2^127, which is out of range.
+        const LOWER: f64 = i128::MIN as f64;
+        const UPPER: f64 = -(i128::MIN as f64);
+        match *self {
+            Self::IntegerLike(n) => (LOWER..UPPER).contains(&n),
+            Self::Zero(..) => true,
+            _ => false,
+        }
+    }
+
+    /// Snaps the classified float to a whole number according to its variant.
+    ///
+    /// - [`FractionLike`] and [`Subnormal`] values round to zero, keeping their sign.
+    /// - [`IntegerAndFractionalPart`] values round to the nearest integer, with ties
+    ///   rounding away from zero like `f64::round`, so `2.5` becomes `3.0`.
+    /// - Integer-valued variants are returned unchanged.
+    /// - [`Nan`] and [`Infinity`] are returned unchanged, as `f64::NAN` and `±f64::INFINITY`.
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use floating_cat::*;
+    /// assert_eq!(0.5f64.category().round_to_category(), 0.0);
+    /// assert_eq!(1.5f64.category().round_to_category(), 2.0);
+    /// assert_eq!(2.0f64.category().round_to_category(), 2.0);
+    /// ```
+    ///
+    /// [`FractionLike`]: CatFloat::FractionLike
+    /// [`Subnormal`]: CatFloat::Subnormal
+    /// [`IntegerAndFractionalPart`]: CatFloat::IntegerAndFractionalPart
+    /// [`Nan`]: CatFloat::Nan
+    /// [`Infinity`]: CatFloat::Infinity
+    pub fn round_to_category(&self) -> f64 {
+        match *self {
+            Self::FractionLike(n) | Self::Subnormal(n) => 0.0f64.copysign(n),
+            Self::IntegerAndFractionalPart(int, fract) if fract.abs() >= 0.5 => {
+                int + 1.0f64.copysign(fract)
+            }
+            Self::IntegerAndFractionalPart(int, _) => int,
+            _ => self.to_f64(),
+        }
+    }
+
+    /// Returns the classified float as `(integer, fraction, special)`, whatever the variant.
+    ///
+    /// Finite values give their [`integer_part`] and [`fractional_part`] with `special` set to
+    /// `None`. [`Nan`] and [`Infinity`] give `(0.0, 0.0, Some(_))`; use [`sign`] to tell the
+    /// infinities apart.
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use floating_cat::*;
+    /// assert_eq!(3.0f64.category().parts(), (3.0, 0.0, None));
+    /// assert_eq!(0.5f64.category().parts(), (0.0, 0.5, None));
+    /// assert_eq!(f64::NAN.category().parts(), (0.0, 0.0, Some(Special::Nan)));
+    /// ```
+    ///
+    /// [`integer_part`]: CatFloat::integer_part
+    /// [`fractional_part`]: CatFloat::fractional_part
+    /// [`Nan`]: CatFloat::Nan
+    /// [`Infinity`]: CatFloat::Infinity
+    /// [`sign`]: CatFloat::sign
+    pub fn parts(&self) -> (f64, f64, Option<Special>) {
+        match (*self, self.integer_part(), self.fractional_part()) {
+            (_, Some(int), Some(fract)) => (int, fract, None),
+            (Self::Nan { .. }, ..) => (0.0, 0.0, Some(Special::Nan)),
+            _ => (0.0, 0.0, Some(Special::Infinity)),
+        }
+    }
+
+    /// Returns the magnitude of the fractional part, in `0.0..1.0`, or `None` for [`Nan`] and
+    /// [`Infinity`].
+    ///
+    /// Negative values give the same result as their positive counterparts, so `-1.25` and
+    /// `1.25` both give `0.25`. Integer-valued variants give `0.0`.
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use floating_cat::*;
+    /// assert_eq!(1.25f64.category().fractional_fraction(), Some(0.25));
+    /// assert_eq!((-1.25f64).category().fractional_fraction(), Some(0.25));
+    /// ```
+    ///
+    /// [`Nan`]: CatFloat::Nan
+    /// [`Infinity`]: CatFloat::Infinity
+    pub fn fractional_fraction(&self) -> Option<f64> {
+        self.fractional_part().map(f64::abs)
+    }
+
+    /// Returns `true` if the classified float is a positive, exact power of two, like `0.5`,
+    /// `2.0` or `1024.0`.
+    ///
+    /// This goes by the payload's bits: the mantissa must be all zero with a normal exponent,
+    /// so subnormals, zeros, negative values and special values are never powers of two.
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use floating_cat::*;
+    /// assert!(0.25f64.category().is_power_of_two());
+    /// assert!(!3.0f64.category().is_power_of_two());
+    /// ```
+    #[must_use]
+    pub fn is_power_of_two(&self) -> bool {
+        match *self {
+            Self::IntegerLike(n) | Self::FractionLike(n) | Self::HugeInteger(n) => {
+                let (negative, exponent, mantissa) = decompose_ieee754(n);
+                !negative && exponent != 0 && mantissa == 0
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the `signum` of the integer and fractional parts, or `None` for a part the
+    /// variant doesn't have. [`Nan`] and [`Infinity`] give `(None, None)`.
+    ///
+    /// Unlike `f64::signum`, zeros keep their value, so [`Zero`]`(Negative)` gives
+    /// `(Some(-0.0), None)` rather than `-1.0`.
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use floating_cat::*;
+    /// assert_eq!((-1.5f64).category().signum_parts(), (Some(-1.0), Some(-1.0)));
+    /// assert_eq!((-0.5f64).category().signum_parts(), (None, Some(-1.0)));
+    /// ```
+    ///
+    /// [`Nan`]: CatFloat::Nan
+    /// [`Infinity`]: CatFloat::Infinity
+    /// [`Zero`]: CatFloat::Zero
+    pub fn signum_parts(&self) -> (Option<f64>, Option<f64>) {
+        let signum = |n: f64| if n == 0.0 { n } else { n.signum() };
+        match *self {
+            Self::IntegerLike(n) | Self::HugeInteger(n) => (Some(signum(n)), None),
+            Self::FractionLike(n) | Self::Subnormal(n) => (None, Some(signum(n))),
+            Self::IntegerAndFractionalPart(int, fract) => (Some(signum(int)), Some(signum(fract))),
+            Self::Zero(..) => (Some(self.to_f64()), None),
+            Self::Nan { .. } | Self::Infinity(..) => (None, None),
+        }
+    }
+
+    /// Returns the classification of the absolute value, without re-classifying.
+    ///
+    /// Every payload becomes non-negative, and [`Infinity`] and [`Zero`] become positive.
+    /// [`Nan`] is returned as-is.
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use floating_cat::*;
+    /// assert_eq!(
+    ///     CatFloat::IntegerAndFractionalPart(-1.0, -0.5).abs(),
+    ///     CatFloat::IntegerAndFractionalPart(1.0, 0.5)
+    /// );
+    /// ```
+    ///
+    /// [`Infinity`]: CatFloat::Infinity
+    /// [`Zero`]: CatFloat::Zero
+    /// [`Nan`]: CatFloat::Nan
+    pub fn abs(&self) -> CatFloat {
+        match *self {
+            Self::Infinity(..) => Self::Infinity(Sign::Positive),
+            Self::Zero(..) => Self::Zero(Sign::Positive),
+            cat => cat.map_parts(f64::abs),
+        }
+    }
+
+    /// Returns a stable numeric tag for the variant, for passing across an FFI boundary.
+    ///
+    /// These values will not change:
+    ///
+    /// | Variant                      | Index |
+    /// |------------------------------|-------|
+    /// | [`IntegerLike`]              | 0     |
+    /// | [`FractionLike`]             | 1     |
+    /// | [`IntegerAndFractionalPart`] | 2     |
+    /// | [`Nan`]                      | 3     |
+    /// | [`Infinity`]                 | 4     |
+    /// | [`Zero`]                     | 5     |
+    /// | [`Subnormal`]                | 6     |
+    /// | [`HugeInteger`]              | 7     |
+    ///
+    /// [`IntegerLike`]: CatFloat::IntegerLike
+    /// [`FractionLike`]: CatFloat::FractionLike
+    /// [`IntegerAndFractionalPart`]: CatFloat::IntegerAndFractionalPart
+    /// [`Nan`]: CatFloat::Nan
+    /// [`Infinity`]: CatFloat::Infinity
+    /// [`Zero`]: CatFloat::Zero
+    /// [`Subnormal`]: CatFloat::Subnormal
+    /// [`HugeInteger`]: CatFloat::HugeInteger
+    pub const fn variant_index(&self) -> u8 {
+        match self {
+            Self::IntegerLike(..) => 0,
+            Self::FractionLike(..) => 1,
+            Self::IntegerAndFractionalPart(..) => 2,
+            Self::Nan { .. } => 3,
+            Self::Infinity(..) => 4,
+            Self::Zero(..) => 5,
+            Self::Subnormal(..) => 6,
+            Self::HugeInteger(..) => 7,
+        }
+    }
+
+    /// The inverse of [`variant_index`]: a placeholder of that variant, or `None` for an
+    /// unknown index.
+    ///
+    /// Placeholders hold `0.0` payloads, a quiet NaN with payload `0`, and [`Sign::Positive`].
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use floating_cat::*;
+    /// let cat = CatFloat::from_variant_index(1).unwrap();
+    /// assert!(cat.is_fraction_like());
+    /// assert_eq!(CatFloat::from_variant_index(200), None);
+    /// ```
+    ///
+    /// [`variant_index`]: CatFloat::variant_index
+    pub const fn from_variant_index(index: u8) -> Option<CatFloat> {
+        Some(match index {
+            0 => Self::IntegerLike(0.0),
+            1 => Self::FractionLike(0.0),
+            2 => Self::IntegerAndFractionalPart(0.0, 0.0),
+            3 => Self::Nan {
+                kind: NanKind::Quiet,
+                payload: 0,
+            },
+            4 => Self::Infinity(Sign::Positive),
+            5 => Self::Zero(Sign::Positive),
+            6 => Self::Subnormal(0.0),
+            7 => Self::HugeInteger(0.0),
+            _ => return None,
+        })
+    }
+
+    /// Returns the classification of `1.0 / self`.
+    ///
+    /// The value is rebuilt with [`to_f64`], inverted and classified again, so zeros become
+    /// signed [`Infinity`], infinities become signed [`Zero`], and `0.5` becomes
+    /// [`IntegerLike`]`(2.0)`.
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use floating_cat::*;
+    /// assert_eq!(0.5f64.category().reciprocal_category(), CatFloat::IntegerLike(2.0));
+    /// assert_eq!(2.0f64.category().reciprocal_category(), CatFloat::FractionLike(0.5));
+    /// ```
+    ///
+    /// [`to_f64`]: CatFloat::to_f64
+    /// [`Infinity`]: CatFloat::Infinity
+    /// [`Zero`]: CatFloat::Zero
+    /// [`IntegerLike`]: CatFloat::IntegerLike
+    pub fn reciprocal_category(&self) -> CatFloat {
+        (1.0 / self.to_f64()).category()
+    }
+
+    /// Returns `true` if the classified float is a whole number exactly equal to `n`.
+    ///
+    /// The comparison is done in `i64`, not by rounding `n` to `f64`, so integers above 2^53
+    /// that a float can't hold never compare equal to their nearest float.
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use floating_cat::*;
+    /// assert!(3.0f64.category().eq_int(3));
+    /// assert!(!9007199254740992.0f64.category().eq_int(9007199254740993));
+    /// ```
+    pub fn eq_int(&self, n: i64) -> bool {
+        self.try_as_i64() == Ok(n)
+    }
+
+    /// Returns `value - value.floor()`, which is always in `0.0..1.0`, or `None` for [`Nan`] and
+    /// [`Infinity`].
+    ///
+    /// Unlike [`fractional_part`], negative values wrap around, so `-1.25` gives `0.75`.
+    /// Negative fractions too small to survive adding `1.0` give the largest `f64` below `1.0`,
+    /// rather than `1.0` itself.
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use floating_cat::*;
+    /// assert_eq!((-1.25f64).category().wrapped_fraction(), Some(0.75));
+    /// assert_eq!(1.25f64.category().wrapped_fraction(), Some(0.25));
+    /// ```
+    ///
+    /// [`Nan`]: CatFloat::Nan
+    /// [`Infinity`]: CatFloat::Infinity
+    /// [`fractional_part`]: CatFloat::fractional_part
+    pub fn wrapped_fraction(&self) -> Option<f64> {
+        const BELOW_ONE: f64 = 1.0 - f64::EPSILON / 2.0;
+
+        let fract = self.fractional_part()?;
+        if fract >= 0.0 {
+            return Some(fract + 0.0);
+        }
+        Some((fract + 1.0).min(BELOW_ONE))
+    }
+
+    /// Returns the classification of the negated value, without re-classifying.
+    ///
+    /// Every payload changes sign, and [`Infinity`] and [`Zero`] flip their [`Sign`].
+    /// [`Nan`] is returned as-is.
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use floating_cat::*;
+    /// assert_eq!(CatFloat::IntegerLike(3.0).negate(), CatFloat::IntegerLike(-3.0));
+    /// assert_eq!(
+    ///     CatFloat::Infinity(Sign::Positive).negate(),
+    ///     CatFloat::Infinity(Sign::Negative)
+    /// );
+    /// ```
+    ///
+    /// [`Infinity`]: CatFloat::Infinity
+    /// [`Zero`]: CatFloat::Zero
+    /// [`Nan`]: CatFloat::Nan
+    pub fn negate(&self) -> CatFloat {
+        let flip = |sign| match sign {
+            Sign::Positive => Sign::Negative,
+            Sign::Negative => Sign::Positive,
+        };
+        match *self {
+            Self::Infinity(sign) => Self::Infinity(flip(sign)),
+            Self::Zero(sign) => Self::Zero(flip(sign)),
+            cat => cat.map_parts(|n| -n),
+        }
+    }
+
+    /// Returns `true` if the Classified float's fractional part is exactly `±0.5`, like `0.5`
+    /// or `-2.5`.
+    #[must_use]
+    pub fn is_exact_half(&self) -> bool {
+        matches!(
+            *self,
+            Self::FractionLike(fract) | Self::IntegerAndFractionalPart(_, fract)
+                if fract.abs() == 0.5
+        )
+    }
+
+    /// Converts an integer-valued classification into any primitive integer type, or `None`
+    /// if it has a fractional part, is special, or is out of `T`'s range.
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use floating_cat::*;
+    /// assert_eq!(200.0f64.category().checked_as::<u8>(), Some(200));
+    /// assert_eq!(300.0f64.category().checked_as::<u8>(), None);
+    /// ```
+    pub fn checked_as<T: TryFromFloat>(&self) -> Option<T> {
+        match *self {
+            Self::IntegerLike(n) | Self::HugeInteger(n) => T::try_from_whole(n),
+            Self::Zero(..) => T::try_from_whole(0.0),
+            _ => None,
+        }
+    }
+
+    /// Returns the `(trunc, fract)` pair the float was split into, whatever the variant, or
+    /// `None` for [`Nan`] and [`Infinity`].
+    ///
+    /// Parts a variant doesn't store are filled in as zero, as by [`integer_part`] and
+    /// [`fractional_part`].
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use floating_cat::*;
+    /// assert_eq!(3.0f64.category().raw_split(), Some((3.0, 0.0)));
+    /// assert_eq!(0.5f64.category().raw_split(), Some((0.0, 0.5)));
+    /// ```
+    ///
+    /// [`Nan`]: CatFloat::Nan
+    /// [`Infinity`]: CatFloat::Infinity
+    /// [`integer_part`]: CatFloat::integer_part
+    /// [`fractional_part`]: CatFloat::fractional_part
+    pub fn raw_split(&self) -> Option<(f64, f64)> {
+        Some((self.integer_part()?, self.fractional_part()?))
+    }
+
+    /// Consumes the classification, returning `(integer, fraction, is_nan, is_infinity)`.
+    ///
+    /// `integer` and `fraction` are `None` when the variant doesn't store that part, so
+    /// [`IntegerLike`] gives `(Some(n), None, false, false)` and [`Nan`] gives
+    /// `(None, None, true, false)`. [`Zero`] counts as an integer part.
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use floating_cat::*;
+    /// let (int, fract, is_nan, is_infinity) = 1.5f64.category().into_parts();
+    /// assert_eq!((int, fract, is_nan, is_infinity), (Some(1.0), Some(0.5), false, false));
+    /// ```
+    ///
+    /// [`IntegerLike`]: CatFloat::IntegerLike
+    /// [`Nan`]: CatFloat::Nan
+    /// [`Zero`]: CatFloat::Zero
+    pub fn into_parts(self) -> (Option<f64>, Option<f64>, bool, bool) {
+        match self {
+            Self::IntegerLike(n) | Self::HugeInteger(n) => (Some(n), None, false, false),
+            Self::FractionLike(n) | Self::Subnormal(n) => (None, Some(n), false, false),
+            Self::IntegerAndFractionalPart(int, fract) => (Some(int), Some(fract), false, false),
+            Self::Zero(..) => (Some(self.to_f64()), None, false, false),
+            Self::Nan { .. } => (None, None, true, false),
+            Self::Infinity(..) => (None, None, false, true),
+        }
+    }
+
+    /// Returns the classification of the midpoint between `self` and `other`.
+    ///
+    /// Both are rebuilt with [`to_f64`] and averaged without overflowing for large finite
+    /// inputs, halving before adding when either is huge, like `f64::midpoint`.
+    /// Any [`Nan`] gives a [`Nan`], as do opposite infinities.
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use floating_cat::*;
+    /// let mid = CatFloat::IntegerLike(1.0).midpoint_category(&CatFloat::IntegerLike(2.0));
+    /// assert_eq!(mid, CatFloat::IntegerAndFractionalPart(1.0, 0.5));
+    /// ```
+    ///
+    /// [`to_f64`]: CatFloat::to_f64
+    /// [`Nan`]: CatFloat::Nan
+    pub fn midpoint_category(&self, other: &CatFloat) -> CatFloat {
+        // `f64::midpoint` needs Rust 1.85; this is the same computation.
+        const LO: f64 = f64::MIN_POSITIVE * 2.0;
+        const HI: f64 = f64::MAX / 2.0;
+
+        let (a, b) = (self.to_f64(), other.to_f64());
+        let (abs_a, abs_b) = (FloatCore::abs(a), FloatCore::abs(b));
+        let mid = if abs_a <= HI && abs_b <= HI {
+            (a + b) / 2.0
+        } else if abs_a < LO {
+            // Halving a tiny value would lose its low bits.
+            a + b / 2.0
+        } else if abs_b < LO {
+            a / 2.0 + b
+        } else {
+            a / 2.0 + b / 2.0
+        };
+        mid.category()
+    }
+
+    /// Returns the payload of a [`Nan`], or `None` for every other variant.
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use floating_cat::*;
+    /// let nan = f64::from_bits(f64::NAN.to_bits() | 0xbeef);
+    /// assert_eq!(nan.category().nan_payload(), Some(0xbeef));
+    /// assert_eq!(1.0f64.category().nan_payload(), None);
+    /// ```
+    ///
+    /// [`Nan`]: CatFloat::Nan
+    pub fn nan_payload(&self) -> Option<u64> {
+        match *self {
+            Self::Nan { payload, .. } => Some(payload),
+            _ => None,
+        }
+    }
+
+    /// Returns the classification of the value multiplied by `factor`.
+    ///
+    /// The value is rebuilt with [`to_f64`], multiplied and classified again, so overflow gives
+    /// [`Infinity`], and NaNs propagate, including the NaN from `0.0 * f64::INFINITY`.
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use floating_cat::*;
+    /// assert_eq!(CatFloat::FractionLike(0.5).scale(4.0), CatFloat::IntegerLike(2.0));
+    /// ```
+    ///
+    /// [`to_f64`]: CatFloat::to_f64
+    /// [`Infinity`]: CatFloat::Infinity
+    pub fn scale(&self, factor: f64) -> CatFloat {
+        (self.to_f64() * factor).category()
+    }
+
+    /// Returns the name of the variant, like `"IntegerLike"`, without any formatting.
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use floating_cat::*;
+    /// assert_eq!(1.5f64.category().variant_name(), "IntegerAndFractionalPart");
+    /// ```
+    pub const fn variant_name(&self) -> &'static str {
+        match self {
+            Self::IntegerLike(..) => "IntegerLike",
+            Self::FractionLike(..) => "FractionLike",
+            Self::IntegerAndFractionalPart(..) => "IntegerAndFractionalPart",
+            Self::Nan { .. } => "Nan",
+            Self::Infinity(..) => "Infinity",
+            Self::Zero(..) => "Zero",
+            Self::Subnormal(..) => "Subnormal",
+            Self::HugeInteger(..) => "HugeInteger",
+        }
+    }
+
+    /// Dispatches to the `visitor` handler matching this variant.
+    ///
+    /// # Examples:
+    /// See [`CatFloatVisitor`].
+    pub fn accept<V: CatFloatVisitor>(&self, visitor: &mut V) -> V::Output {
+        match *self {
+            Self::IntegerLike(n) => visitor.on_integer_like(n),
+            Self::FractionLike(n) => visitor.on_fraction_like(n),
+            Self::IntegerAndFractionalPart(int, fract) => visitor.on_mixed(int, fract),
+            Self::Nan { kind, payload } => visitor.on_nan(kind, payload),
+            Self::Infinity(sign) => visitor.on_infinity(sign),
+            Self::Zero(sign) => visitor.on_zero(sign),
+            Self::Subnormal(n) => visitor.on_subnormal(n),
+            Self::HugeInteger(n) => visitor.on_huge_integer(n),
+        }
+    }
+
+    /// Converts the classified float into an `i64`, clamping instead of failing.
+    ///
+    /// The fractional part is dropped, rounding toward zero, so `3.7` gives `3` and `-3.7`
+    /// gives `-3`. Values past either end of `i64`, including the infinities, clamp to
+    /// `i64::MIN` or `i64::MAX`, and [`Nan`] gives `0`. This is the same as an `as` cast;
+    /// use [`try_as_i64`] to catch these cases instead.
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use floating_cat::*;
+    /// assert_eq!(1e300f64.category().saturating_as_i64(), i64::MAX);
+    /// assert_eq!(3.7f64.category().saturating_as_i64(), 3);
+    /// ```
+    ///
+    /// [`Nan`]: CatFloat::Nan
+    /// [`try_as_i64`]: CatFloat::try_as_i64
+    pub fn saturating_as_i64(&self) -> i64 {
+        match *self {
+            Self::Nan { .. } => 0,
+            Self::Infinity(Sign::Positive) => i64::MAX,
+            Self::Infinity(Sign::Negative) => i64::MIN,
+            _ => self.integer_part().map_or(0, |int| int as i64),
+        }
+    }
+
+    /// Returns the classification of `self op other`.
+    ///
+    /// Both are rebuilt with [`to_f64`], combined with ordinary `f64` arithmetic, and
+    /// classified again, so NaN and infinity propagate as IEEE-754 says: dividing a nonzero
+    /// value by zero gives a signed [`Infinity`], and `0.0 / 0.0` gives [`Nan`].
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use floating_cat::*;
+    /// let sum = CatFloat::IntegerLike(1.0).combine(&CatFloat::FractionLike(0.5), ArithOp::Add);
+    /// assert_eq!(sum, CatFloat::IntegerAndFractionalPart(1.0, 0.5));
+    /// ```
+    ///
+    /// [`to_f64`]: CatFloat::to_f64
+    /// [`Infinity`]: CatFloat::Infinity
+    /// [`Nan`]: CatFloat::Nan
+    pub fn combine(&self, other: &CatFloat, op: ArithOp) -> CatFloat {
+        let (a, b) = (self.to_f64(), other.to_f64());
+        let result = match op {
+            ArithOp::Add => a + b,
+            ArithOp::Sub => a - b,
+            ArithOp::Mul => a * b,
+            ArithOp::Div => a / b,
+        };
+        result.category()
+    }
+
+    /// Returns `true` if the Classified float is a negative [`FractionLike`], like `-0.2`.
+    ///
+    /// [`FractionLike`]: CatFloat::FractionLike
+    #[must_use]
+    pub fn is_negative_fraction(&self) -> bool {
+        matches!(*self, Self::FractionLike(n) if n.is_sign_negative())
+    }
+
+    /// Returns the magnitude of a [`FractionLike`], always in `0.0..1.0`, or `None` for every
+    /// other variant.
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use floating_cat::*;
+    /// assert_eq!((-0.2f64).category().normalized_fraction_like(), Some(0.2));
+    /// ```
+    ///
+    /// [`FractionLike`]: CatFloat::FractionLike
+    pub fn normalized_fraction_like(&self) -> Option<f64> {
+        match *self {
+            Self::FractionLike(n) => Some(n.abs()),
+            _ => None,
+        }
+    }
+
+    /// Encodes the classification as a JSON object that never holds a non-finite number.
+    ///
+    /// The object is `{"kind": .., "int": .., "fract": ..}`, where `kind` is the variant in
+    /// snake case (`"integer_like"`, `"mixed"`, `"nan"`, ...) and `int` and `fract` are the
+    /// [`integer_part`] and [`fractional_part`], or `null` for NaN and infinities.
+    /// Infinities also get a `"sign"` of `"positive"` or `"negative"`.
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use floating_cat::*;
+    /// let json = 1.5f64.category().to_json_value();
+    /// assert_eq!(json.to_string(), r#"{"fract":0.5,"int":1.0,"kind":"mixed"}"#);
+    /// ```
+    ///
+    /// [`integer_part`]: CatFloat::integer_part
+    /// [`fractional_part`]: CatFloat::fractional_part
+    #[cfg(feature = "json")]
+    pub fn to_json_value(&self) -> serde_json::Value {
+        let kind = match self {
+            Self::IntegerLike(..) => "integer_like",
+            Self::FractionLike(..) => "fraction_like",
+            Self::IntegerAndFractionalPart(..) => "mixed",
+            Self::Nan { .. } => "nan",
+            Self::Infinity(..) => "infinity",
+            Self::Zero(..) => "zero",
+            Self::Subnormal(..) => "subnormal",
+            Self::HugeInteger(..) => "huge_integer",
+        };
+
+        let mut value = serde_json::json!({
+            "kind": kind,
+            "int": self.integer_part(),
+            "fract": self.fractional_part(),
+        });
+        if let Self::Infinity(sign) = self {
+            value["sign"] = match sign {
+                Sign::Positive => "positive",
+                Sign::Negative => "negative",
+            }
+            .into();
+        }
+        value
+    }
+
+    /// Snaps a classification within `eps` of a whole number to that whole number.
+    ///
+    /// Only [`FractionLike`], [`IntegerAndFractionalPart`] and [`Subnormal`] values are
+    /// snapped; the rounded value is classified again, so something within `eps` of zero
+    /// becomes [`Zero`]. Everything else, including special values, is returned unchanged.
+    /// See [`category_with_epsilon`] to do this while classifying a raw `f64`.
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use floating_cat::*;
+    /// let near = CatFloat::IntegerAndFractionalPart(2.0, 0.0000001);
+    /// assert_eq!(near.try_reduce_to_integer(1e-6), CatFloat::IntegerLike(2.0));
+    /// ```
+    ///
+    /// [`FractionLike`]: CatFloat::FractionLike
+    /// [`IntegerAndFractionalPart`]: CatFloat::IntegerAndFractionalPart
+    /// [`Subnormal`]: CatFloat::Subnormal
+    /// [`Zero`]: CatFloat::Zero
+    pub fn try_reduce_to_integer(&self, eps: f64) -> CatFloat {
+        match self {
+            Self::FractionLike(..) | Self::IntegerAndFractionalPart(..) | Self::Subnormal(..) => {
+                let x = self.to_f64();
+                let rounded = x.round();
+                if (x - rounded).abs() <= eps {
+                    return rounded.category();
+                }
+                *self
+            }
+            _ => *self,
+        }
+    }
+
+    /// Returns the classification of `a + (b - a) * t`, where `a` is `self` and `b` is `other`.
+    ///
+    /// Both are rebuilt with [`to_f64`]. `t` isn't clamped, so values outside `0.0..=1.0`
+    /// extrapolate past either end. Any [`Nan`] gives a [`Nan`], and so does interpolating
+    /// between infinities, or from an infinity at `t = 0.0`, since `b - a` or `(b - a) * t`
+    /// is NaN there.
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use floating_cat::*;
+    /// let a = CatFloat::Zero(Sign::Positive);
+    /// let b = CatFloat::IntegerLike(2.0);
+    /// assert_eq!(a.lerp_category(&b, 0.25), CatFloat::FractionLike(0.5));
+    /// ```
+    ///
+    /// [`to_f64`]: CatFloat::to_f64
+    /// [`Nan`]: CatFloat::Nan
+    pub fn lerp_category(&self, other: &CatFloat, t: f64) -> CatFloat {
+        let (a, b) = (self.to_f64(), other.to_f64());
+        (a + (b - a) * t).category()
+    }
+
+    /// Iterates over the numeric parts that are present in this classification.
+    ///
+    /// [`IntegerAndFractionalPart`] yields its integer part, then its fractional part. Other
+    /// finite variants, including [`Zero`], [`Subnormal`] and [`HugeInteger`], yield their
+    /// single value. [`Nan`] and [`Infinity`] yield nothing.
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use floating_cat::*;
+    /// assert_eq!(1.5f64.category().parts_iter().sum::<f64>(), 1.5);
+    /// assert_eq!(f64::NAN.category().parts_iter().count(), 0);
+    /// ```
+    ///
+    /// [`IntegerAndFractionalPart`]: CatFloat::IntegerAndFractionalPart
+    /// [`Zero`]: CatFloat::Zero
+    /// [`Subnormal`]: CatFloat::Subnormal
+    /// [`HugeInteger`]: CatFloat::HugeInteger
+    /// [`Nan`]: CatFloat::Nan
+    /// [`Infinity`]: CatFloat::Infinity
+    pub fn parts_iter(&self) -> impl Iterator<Item = f64> {
+        let (first, second) = match *self {
+            Self::IntegerAndFractionalPart(int, fract) => (Some(int), Some(fract)),
+            Self::IntegerLike(n)
+            | Self::FractionLike(n)
+            | Self::Subnormal(n)
+            | Self::HugeInteger(n) => (Some(n), None),
+            Self::Zero(..) => (Some(self.to_f64()), None),
+            Self::Nan { .. } | Self::Infinity(..) => (None, None),
+        };
+        first.into_iter().chain(second)
+    }
+
+    /// Returns the classification of the value's euclidean remainder by `divisor`, which
+    /// always lands in `0.0..divisor.abs()`.
+    ///
+    /// This is `f64::rem_euclid`, so `-1.0` modulo `3.0` is `2.0`, not `-1.0`. A remainder of
+    /// zero is always [`Zero`] with a positive sign, even for negative values. Unlike
+    /// `f64::rem_euclid`, a tiny negative value whose remainder would round up to exactly
+    /// `divisor.abs()` gives that positive zero too, so the range always holds.
+    /// A zero or non-finite `divisor` gives a quiet [`Nan`], as do [`Nan`] and [`Infinity`].
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use floating_cat::*;
+    /// let angle = CatFloat::IntegerAndFractionalPart(7.0, 0.5);
+    /// assert_eq!(angle.classify_modulo(2.0), CatFloat::IntegerAndFractionalPart(1.0, 0.5));
+    /// assert_eq!(CatFloat::IntegerLike(-1.0).classify_modulo(3.0), CatFloat::IntegerLike(2.0));
+    /// ```
+    ///
+    /// [`Zero`]: CatFloat::Zero
+    /// [`Nan`]: CatFloat::Nan
+    /// [`Infinity`]: CatFloat::Infinity
+    pub fn classify_modulo(&self, divisor: f64) -> CatFloat {
+        if !divisor.is_finite() || divisor == 0.0 {
+            return f64::NAN.category();
+        }
+
+        // `f64::rem_euclid` needs std; this is the same computation.
+        let r = self.to_f64() % divisor;
+        let r = if r < 0.0 { r + divisor.abs() } else { r };
+        if r == divisor.abs() {
+            // Only reachable by rounding, as with `-1e-20 mod 1.0`.
+            return CatFloat::Zero(Sign::Positive);
+        }
+        // Adding zero turns a -0.0 remainder into 0.0.
+        (r + 0.0).category()
+    }
+
+    /// Returns how many binary digits after the binary point the value needs, or `None` for
+    /// [`Nan`] and [`Infinity`].
+    ///
+    /// This is the position of the lowest set mantissa bit below the binary point, so `0.5`
+    /// needs 1 bit, `0.75` needs 2 and `0.1` needs 55. Integer-valued variants give `Some(0)`.
+    /// A fraction needing close to 52 bits has no precision left to absorb rounding.
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use floating_cat::*;
+    /// assert_eq!(1.25f64.category().fractional_precision_bits(), Some(2));
+    /// assert_eq!(3.0f64.category().fractional_precision_bits(), Some(0));
+    /// ```
+    ///
+    /// [`Nan`]: CatFloat::Nan
+    /// [`Infinity`]: CatFloat::Infinity
+    pub fn fractional_precision_bits(&self) -> Option<u32> {
+        use ieee754::{EXPONENT_BIAS, MANTISSA_BITS};
+
+        let n = match *self {
+            Self::Nan { .. } | Self::Infinity(..) => return None,
+            Self::IntegerLike(..) | Self::Zero(..) | Self::HugeInteger(..) => return Some(0),
+            _ => self.to_f64(),
+        };
+
+        let (_, biased_exponent, mantissa) = decompose_ieee754(n);
+        // Subnormals have no implicit bit, and the same scale as the smallest exponent.
+        let (exponent, significand) = match biased_exponent {
+            0 => (1 - EXPONENT_BIAS, mantissa),
+            e => (i32::from(e) - EXPONENT_BIAS, mantissa | 1 << MANTISSA_BITS),
+        };
+        // The lowest set bit is worth 2^lowest.
+        let lowest = exponent - MANTISSA_BITS as i32 + significand.trailing_zeros() as i32;
+        Some(lowest.min(0).unsigned_abs())
+    }
+
+    /// Returns how far the value rebuilt by [`to_f64`] is from `original`.
+    ///
+    /// Every variant stores its value exactly, so this is `0.0` whenever `self` is the
+    /// classification of a finite `original`; it's for auditing classifications built by hand
+    /// or by lossy conversions. Infinities and NaNs give NaN, since they can't be subtracted.
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use floating_cat::*;
+    /// assert_eq!(0.1f64.category().round_trip_error(0.1), 0.0);
+    /// assert_eq!(CatFloat::IntegerLike(1.0).round_trip_error(1.25), 0.25);
+    /// ```
+    ///
+    /// [`to_f64`]: CatFloat::to_f64
+    pub fn round_trip_error(&self, original: f64) -> f64 {
+        (self.to_f64() - original).abs()
+    }
+
+    /// Returns the classification of the value clamped to `min..=max`, with `f64::clamp`.
+    ///
+    /// [`Nan`] stays NaN, and infinite bounds are allowed, so clamping to
+    /// `0.0..=f64::INFINITY` only raises negatives to zero.
+    ///
+    /// # Panics
+    /// Panics if `min > max`, or if either bound is NaN.
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use floating_cat::*;
+    /// let x = CatFloat::FractionLike(0.5);
+    /// assert_eq!(x.clamp_to_range(1.0, 2.0), CatFloat::IntegerLike(1.0));
+    /// ```
+    ///
+    /// [`Nan`]: CatFloat::Nan
+    pub fn clamp_to_range(&self, min: f64, max: f64) -> CatFloat {
+        self.to_f64().clamp(min, max).category()
+    }
+
+    /// Keeps the integer part but swaps the fractional part for `new_fract`, then classifies
+    /// the result again.
+    ///
+    /// `new_fract` is a magnitude, and takes the value's sign, so replacing the fraction of
+    /// `-3.5` with `0.25` gives `-3.25`. [`Nan`] and [`Infinity`] have no parts to replace,
+    /// and are returned unchanged.
+    ///
+    /// # Panics
+    /// Panics if `new_fract` isn't in `0.0..1.0`.
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use floating_cat::*;
+    /// let x = CatFloat::IntegerAndFractionalPart(3.0, 0.5);
+    /// assert_eq!(x.with_fraction_replaced(0.25), CatFloat::IntegerAndFractionalPart(3.0, 0.25));
+    /// ```
+    ///
+    /// [`Nan`]: CatFloat::Nan
+    /// [`Infinity`]: CatFloat::Infinity
+    pub fn with_fraction_replaced(&self, new_fract: f64) -> CatFloat {
+        assert!(
+            (0.0..1.0).contains(&new_fract),
+            "new fraction {new_fract} is outside 0.0..1.0"
+        );
+
+        let Some(int) = self.integer_part() else {
+            return *self;
+        };
+        if int.is_sign_negative() {
+            (int - new_fract).category()
+        } else {
+            (int + new_fract).category()
+        }
+    }
+
+    /// Returns up to `max_terms` coefficients of the value's continued fraction
+    /// `a0 + 1 / (a1 + 1 / (a2 + ...))`.
+    ///
+    /// Every finite `f64` is exactly `m * 2^e`, so this runs Euclid's algorithm on that
+    /// numerator and denominator, and stops exactly when the remainder reaches zero. Terms are
+    /// floors, so every term after the first is positive. The expansion also stops before a
+    /// later term too large for an `i64`, so `1e-300` gives just `[0]`.
+    ///
+    /// Returns `None` for [`Nan`] and [`Infinity`], and for values whose first term, the
+    /// floor, doesn't fit in an `i64`, like `1e300`. Otherwise the result only comes back
+    /// empty if `max_terms` is zero.
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use floating_cat::*;
+    /// assert_eq!(2.75f64.category().continued_fraction(10), Some(vec![2, 1, 3]));
+    /// assert_eq!((-1.5f64).category().continued_fraction(10), Some(vec![-2, 2]));
+    /// assert_eq!(1e300f64.category().continued_fraction(10), None);
+    /// ```
+    ///
+    /// [`Nan`]: CatFloat::Nan
+    /// [`Infinity`]: CatFloat::Infinity
+    #[cfg(feature = "alloc")]
+    pub fn continued_fraction(&self, max_terms: usize) -> Option<alloc::vec::Vec<i64>> {
+        let mut terms = exact_continued_fraction(self.to_f64())?.map(i64::try_from);
+        let first = terms.next()?.ok()?;
+
+        let rest = terms.map_while(Result::ok);
+        Some(
+            core::iter::once(first)
+                .chain(rest)
+                .take(max_terms)
+                .collect(),
+        )
+    }
+}
+
+/// Compares the variant plus the bits of each payload, the same way [`Hash`] does.
+///
+/// `-0.0` and `0.0` payloads are equal, and so are any two NaN payloads. That only matters
+/// for hand-built values like `FractionLike(f64::NAN)`: classified zeros are always
+/// [`CatFloat::Zero`], whose [`Sign`] keeps them apart, and only [`CatFloat::Nan`] holds NaN.
+/// [`CatFloat::Nan`] compares its kind and payload.
+impl PartialEq for CatFloat {
+    fn eq(&self, other: &Self) -> bool {
+        let same = |a: f64, b: f64| normalized_bits(a) == normalized_bits(b);
+
+        match (*self, *other) {
+            (Self::IntegerLike(a), Self::IntegerLike(b))
+            | (Self::FractionLike(a), Self::FractionLike(b))
+            | (Self::Subnormal(a), Self::Subnormal(b))
+            | (Self::HugeInteger(a), Self::HugeInteger(b)) => same(a, b),
+            (
+                Self::IntegerAndFractionalPart(a_int, a_fract),
+                Self::IntegerAndFractionalPart(b_int, b_fract),
+            ) => same(a_int, b_int) && same(a_fract, b_fract),
+            (Self::Infinity(a), Self::Infinity(b)) | (Self::Zero(a), Self::Zero(b)) => a == b,
+            (
+                Self::Nan {
+                    kind: a_kind,
+                    payload: a_payload,
+                },
+                Self::Nan {
+                    kind: b_kind,
+                    payload: b_payload,
+                },
+            ) => a_kind == b_kind && a_payload == b_payload,
+            _ => false,
+        }
+    }
+}
+
+/// Payloads are compared by normalized bits, so every value equals itself, including
+/// hand-built ones holding NaN. Equality is reflexive.
+impl Eq for CatFloat {}
+
+/// Hashes the variant plus the bits of each payload, consistently with `==`.
+///
+/// `-0.0` and `0.0` payloads hash the same, and so do all NaN payloads.
+impl Hash for CatFloat {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let hash_f64 = |n: f64, state: &mut H| normalized_bits(n).hash(state);
+
+        core::mem::discriminant(self).hash(state);
+        match *self {
+            Self::IntegerLike(n)
+            | Self::FractionLike(n)
+            | Self::Subnormal(n)
+            | Self::HugeInteger(n) => hash_f64(n, state),
+            Self::IntegerAndFractionalPart(int, fract) => {
+                hash_f64(int, state);
+                hash_f64(fract, state);
+            }
+            Self::Infinity(sign) | Self::Zero(sign) => sign.hash(state),
+            Self::Nan { kind, payload } => {
+                kind.hash(state);
+                payload.hash(state);
+            }
+        }
+    }
+}
+
+/// Defaults to [`CatFloat::EMPTY`], which is `0.0.category()`.
+///
+/// [`Zero`](CatFloat::Zero) is used rather than `IntegerLike(0.0)` because that is what the
+/// classifier itself produces for zero, so a default slot compares equal to a real `0.0`.
+impl Default for CatFloat {
+    fn default() -> Self {
+        Self::EMPTY
+    }
+}
+
+/// Orders classifications by the value they were built from, like `f64`'s `partial_cmp`.
+///
+/// Any comparison involving [`CatFloat::Nan`] returns `None`, and infinities sit at the
+/// extremes. To stay consistent with `==`, [`CatFloat::Zero`]`(Negative)` sorts just before
+/// [`CatFloat::Zero`]`(Positive)`, and unequal hand-built values with the same value, like
+/// `IntegerLike(0.0)` and `Zero(Positive)`, are incomparable.
+/// Use [`CatFloat::total_cmp`] for a total order.
+///
+/// # Examples:
+/// ```rust
+/// # use floating_cat::*;
+/// assert!(1.0f64.category() < 1.5f64.category());
+/// assert_eq!(f64::NAN.category().partial_cmp(&1.0f64.category()), None);
+/// ```
+impl PartialOrd for CatFloat {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.is_nan() || other.is_nan() {
+            return None;
+        }
+        match self.to_f64().partial_cmp(&other.to_f64())? {
+            Ordering::Equal if self != other => {
+                Some(self.total_cmp(other)).filter(|o| *o != Ordering::Equal)
+            }
+            ordering => Some(ordering),
+        }
+    }
+}
+
+impl core::fmt::Display for CatFloat {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::IntegerLike(n) => write!(f, "integer-like ({n})"),
+            Self::FractionLike(n) => write!(f, "fraction-like ({n})"),
+            Self::IntegerAndFractionalPart(int, fract) => write!(f, "{int} + {fract}"),
+            Self::Nan {
+                kind: NanKind::Quiet,
+                ..
+            } => write!(f, "NaN"),
+            Self::Nan {
+                kind: NanKind::Signaling,
+                ..
+            } => write!(f, "signaling NaN"),
+            Self::Infinity(Sign::Positive) => write!(f, "infinity"),
+            Self::Infinity(Sign::Negative) => write!(f, "-infinity"),
+            Self::Zero(Sign::Positive) => write!(f, "zero"),
+            Self::Zero(Sign::Negative) => write!(f, "-zero"),
+            Self::Subnormal(n) => write!(f, "subnormal ({n:e})"),
+            Self::HugeInteger(n) => write!(f, "huge integer ({n:e})"),
+        }
+    }
+}
+
+/// Reasons a [`CatFloat`] couldn't be converted into an integer.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum CastError {
+    /// The float had a fractional part.
+    NotInteger,
+
+    /// The float was integer-like, but too large for the target type.
+    OutOfRange,
+
+    /// The float was NaN.
+    WasNaN,
+
+    /// The float was Infinity.
+    WasInfinite,
+}
+
+impl core::fmt::Display for CastError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NotInteger => write!(f, "float has a fractional part"),
+            Self::OutOfRange => write!(f, "float is out of range for the integer type"),
+            Self::WasNaN => write!(f, "float was NaN"),
+            Self::WasInfinite => write!(f, "float was infinite"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CastError {}
+
+/// Primitive integers that [`CatFloat::checked_as`] can convert into.
+pub trait TryFromFloat: Sized {
+    /// Converts a whole-number `f64`, or returns `None` if it's out of range.
+    fn try_from_whole(n: f64) -> Option<Self>;
+}
+
+macro_rules! impl_try_from_float {
+    ($($int:ty),*) => {$(
+        impl TryFromFloat for $int {
+            fn try_from_whole(n: f64) -> Option<Self> {
+                // MIN is 0 or a power of two, so it's exact. MAX may round up to a power of
+                // two, and adding one keeps the bound exclusive either way.
+                const LOWER: f64 = <$int>::MIN as f64;
+                const UPPER: f64 = <$int>::MAX as f64 + 1.0;
+                (LOWER..UPPER).contains(&n).then(|| n as $int)
+            }
+        }
+    )*};
+}
+
+impl_try_from_float!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+
+/// The reason [`category_strict`] rejected a float.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum NonFinite {
+    /// The float was NaN.
+    WasNaN,
+
+    /// The float was Infinity.
+    WasInfinite,
+}
+
+impl core::fmt::Display for NonFinite {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::WasNaN => write!(f, "float was NaN"),
+            Self::WasInfinite => write!(f, "float was infinite"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NonFinite {}
+
+/// Same as [`Category::category`].
+impl From<f64> for CatFloat {
+    fn from(x: f64) -> Self {
+        x.category()
+    }
+}
+
+/// Rebuilds the classified value with [`CatFloat::to_f64`], failing for [`CatFloat::Nan`].
+impl TryFrom<CatFloat> for f64 {
+    type Error = CastError;
+
+    fn try_from(cat: CatFloat) -> Result<Self, Self::Error> {
+        if cat.is_nan() {
+            return Err(CastError::WasNaN);
+        }
+        Ok(cat.to_f64())
+    }
+}
+
+/// Parses the string as an `f64` and classifies it, exactly like [`classify_str`].
+///
+/// # Examples:
+/// ```rust
+/// # use floating_cat::*;
+/// assert_eq!("0.5".parse::<CatFloat>(), Ok(CatFloat::FractionLike(0.5)));
+/// ```
+impl core::str::FromStr for CatFloat {
+    type Err = ParseFloatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        classify_str(s)
+    }
+}
+
+/// Builds a classification by classifying an arbitrary `f64` bit pattern, so every
+/// generated value is one [`Category::category`] can actually produce.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for CatFloat {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(classify_bits(u.arbitrary()?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <u64 as arbitrary::Arbitrary>::size_hint(depth)
+    }
+}
+
+/// Classifies with [`category_fast`].
+///
+/// Both zeros are [`CatFloat::Zero`], whose [`Sign`] keeps `-0.0` apart from `0.0` even
+/// though they compare equal as floats. Tiny negatives stay nonzero: `-5e-324` is
+/// [`CatFloat::Subnormal`] and `-1e-300` is [`CatFloat::FractionLike`], each with its sign.
+impl Category for f64 {
+    type D = CatFloat;
+    fn category(&self) -> Self::D {
+        category_fast(*self)
+    }
+}
+
+/// Classifies the float inside, if there is one.
+///
+/// # Examples:
+/// ```rust
+/// # use floating_cat::*;
+/// assert_eq!(Some(0.5).category(), Some(CatFloat::FractionLike(0.5)));
+/// assert_eq!(None::<f64>.category(), None);
+/// ```
+impl Category for Option<f64> {
+    type D = Option<CatFloat>;
+    fn category(&self) -> Self::D {
+        self.map(category_of)
+    }
+}
+
+/// Classifies the float inside an `Ok`, passing a clone of any `Err` through untouched.
+///
+/// # Examples:
+/// ```rust
+/// # use floating_cat::*;
+/// let x: Result<f64, &str> = Ok(0.5);
+/// assert_eq!(x.category(), Ok(CatFloat::FractionLike(0.5)));
+/// ```
+impl<E: Clone> Category for Result<f64, E> {
+    type D = Result<CatFloat, E>;
+    fn category(&self) -> Self::D {
+        self.clone().map(category_of)
+    }
+}
+
+/// Same as the `f64` impl, so generic code bounded by [`Category`] accepts borrowed floats.
+///
+/// # Examples:
+/// ```rust
+/// # use floating_cat::*;
+/// fn classify<T: Category>(x: T) -> T::D {
+///     x.category()
+/// }
+/// assert_eq!(classify(&1.5), CatFloat::IntegerAndFractionalPart(1.0, 0.5));
+/// ```
+impl Category for &f64 {
+    type D = CatFloat;
+    fn category(&self) -> Self::D {
+        category_fast(**self)
+    }
+}
+
+/// Same as the `f64` impl, for boxed floats.
+#[cfg(feature = "alloc")]
+impl Category for alloc::boxed::Box<f64> {
+    type D = CatFloat;
+    fn category(&self) -> Self::D {
+        category_fast(**self)
+    }
+}
+
+/// Classifies the real and imaginary parts separately, as `(re, im)`.
+///
+/// # Examples:
+/// ```rust
+/// # use floating_cat::*;
+/// let z = num::Complex::new(1.5, -0.5);
+/// assert_eq!(
+///     z.category(),
+///     (CatFloat::IntegerAndFractionalPart(1.0, 0.5), CatFloat::FractionLike(-0.5))
+/// );
+/// ```
+#[cfg(feature = "complex")]
+impl Category for num::Complex<f64> {
+    type D = (CatFloat, CatFloat);
+    fn category(&self) -> Self::D {
+        (category_fast(self.re), category_fast(self.im))
+    }
+}
+
+/// Classifies any float type implementing `num`'s `FloatCore`, widening its parts to `f64`.
+///
+/// `f64` and `f32` implement [`Category`] directly; this covers every other float type,
+/// and works without `std`.
+///
+/// # Examples:
+/// ```rust
+/// # use floating_cat::*;
+/// assert_eq!(category_of(1.5f32), CatFloat::IntegerAndFractionalPart(1.0, 0.5));
+/// ```
+pub fn category_of<F: FloatCore>(x: F) -> CatFloat {
+    let widen = |x: F| x.to_f64().expect("float types always convert to f64");
+
+    if x.is_infinite() {
+        if x.is_sign_negative() {
+            return CatFloat::Infinity(Sign::Negative);
+        }
+        return CatFloat::Infinity(Sign::Positive);
+    }
+    if x.is_nan() {
+        let (kind, payload) = nan_parts_of(x);
+        return CatFloat::Nan { kind, payload };
+    }
+    if x.is_zero() {
+        if x.is_sign_negative() {
+            return CatFloat::Zero(Sign::Negative);
+        }
+        return CatFloat::Zero(Sign::Positive);
+    }
+    if x.classify() == FpCategory::Subnormal {
+        return CatFloat::Subnormal(widen(x));
+    }
+
+    let int_part: F = x.trunc();
+    let fract_part: F = x.fract();
+
+    if fract_part.is_zero() {
+        let int_part = widen(int_part);
+        if is_huge(int_part) {
+            return CatFloat::HugeInteger(int_part);
+        }
+        CatFloat::IntegerLike(int_part)
+    } else if int_part.is_zero() {
+        CatFloat::FractionLike(widen(fract_part))
+    } else {
+        CatFloat::IntegerAndFractionalPart(widen(int_part), widen(fract_part))
+    }
+}
+
+/// Classifies an `f64` the same way [`Category::category`] does, but in a `const` context.
+///
+/// # Examples:
+/// ```rust
+/// # use floating_cat::*;
+/// const C: CatFloat = classify_f64(1.5);
+/// assert_eq!(C, CatFloat::IntegerAndFractionalPart(1.0, 0.5));
+/// ```
+pub const fn classify_f64(x: f64) -> CatFloat {
+    if x.is_infinite() {
+        if x.is_sign_negative() {
+            return CatFloat::Infinity(Sign::Negative);
+        }
+        return CatFloat::Infinity(Sign::Positive);
+    }
+    if x.is_nan() {
+        return nan_of_bits(x.to_bits());
+    }
+    if x == 0.0 {
+        if x.is_sign_negative() {
+            return CatFloat::Zero(Sign::Negative);
+        }
+        return CatFloat::Zero(Sign::Positive);
+    }
+    if x.is_subnormal() {
+        return CatFloat::Subnormal(x);
+    }
+
+    let int_part: f64 = const_trunc(x);
+    let fract_part: f64 = x - int_part;
+
+    if fract_part == 0.0 {
+        if is_huge(int_part) {
+            return CatFloat::HugeInteger(int_part);
+        }
+        CatFloat::IntegerLike(int_part)
+    } else if int_part == 0.0 {
+        CatFloat::FractionLike(fract_part)
+    } else {
+        CatFloat::IntegerAndFractionalPart(int_part, fract_part)
+    }
+}
+
+/// Classifies `x`, treating it as integer-like if it's within `eps` of an integer.
+///
+/// Snapped values are rounded to the nearest integer and classified again,
+/// so something within `eps` of zero becomes [`CatFloat::Zero`].
+/// NaN and the infinities ignore `eps`.
+///
+/// # Examples:
+/// ```rust
+/// # use floating_cat::*;
+/// assert_eq!(category_with_epsilon(2.9999999996, 1e-6), CatFloat::IntegerLike(3.0));
+/// assert_eq!(category_with_epsilon(2.5, 1e-6), CatFloat::IntegerAndFractionalPart(2.0, 0.5));
+/// ```
+pub fn category_with_epsilon(x: f64, eps: f64) -> CatFloat {
+    let (int_part, fract_part) = match x.category() {
+        CatFloat::IntegerAndFractionalPart(int, fract) => (int, fract),
+        CatFloat::FractionLike(fract) | CatFloat::Subnormal(fract) => (0.0, fract),
+        other => return other,
+    };
+
+    // `fract_part` has the same sign as `x`, so the nearest integer away from zero
+    // is one step further in that direction.
+    let magnitude = fract_part.abs();
+    if magnitude < eps {
+        int_part.copysign(x).category()
+    } else if 1.0 - magnitude < eps {
+        (int_part + fract_part.signum()).category()
+    } else {
+        x.category()
+    }
+}
+
+/// Classifies `x` like [`Category::category`], but splits it with `floor` instead of `trunc`,
+/// so the fractional part is always in `[0, 1)`.
+///
+/// Negative values get a larger-magnitude integer part: `-1.5` becomes `-2.0` and `0.5`,
+/// and `-0.5` becomes `-1.0` and `0.5`.
+/// A negative value within rounding distance of the integer above it (like `-1e-20`)
+/// would need a fraction of exactly `1.0`, so it's classified as that integer instead.
+/// That includes every negative subnormal, which becomes [`CatFloat::Zero`]`(Negative)`;
+/// positive subnormals are already in `[0, 1)` and stay [`CatFloat::Subnormal`].
+///
+/// # Examples:
+/// ```rust
+/// # use floating_cat::*;
+/// assert_eq!(category_floor_based(-1.5), CatFloat::IntegerAndFractionalPart(-2.0, 0.5));
+/// ```
+pub fn category_floor_based(x: f64) -> CatFloat {
+    match x.category() {
+        CatFloat::IntegerAndFractionalPart(..) | CatFloat::FractionLike(..) => {}
+        CatFloat::Subnormal(n) if n < 0.0 => {}
+        other => return other,
+    }
+
+    let int_part = x.floor();
+    let fract_part = x - int_part;
+
+    if fract_part >= 1.0 {
+        (int_part + 1.0).copysign(x).category()
+    } else if int_part == 0.0 {
+        CatFloat::FractionLike(fract_part)
+    } else {
+        CatFloat::IntegerAndFractionalPart(int_part, fract_part)
+    }
+}
+
+/// Parses `s` as an `f64` and classifies it.
+///
+/// Parsing is exactly `s.parse::<f64>()`, so `"inf"`, `"-infinity"` and `"NaN"` are accepted.
+///
+/// # Examples:
+/// ```rust
+/// # use floating_cat::*;
+/// assert_eq!(classify_str("1.5"), Ok(CatFloat::IntegerAndFractionalPart(1.0, 0.5)));
+/// assert!(classify_str("one").is_err());
+/// ```
+pub fn classify_str(s: &str) -> Result<CatFloat, ParseFloatError> {
+    s.parse::<f64>().map(|x| x.category())
+}
+
+/// Parses and classifies `s` like [`classify_str`], also reporting whether the text was
+/// written as a float: with a `.` or an exponent.
+///
+/// That tells `"3"` apart from `"3.0"`, which classify the same.
+///
+/// # Examples:
+/// ```rust
+/// # use floating_cat::*;
+/// assert_eq!(classify_literal("3"), Ok((CatFloat::IntegerLike(3.0), false)));
+/// assert_eq!(classify_literal("3.0"), Ok((CatFloat::IntegerLike(3.0), true)));
+/// ```
+pub fn classify_literal(s: &str) -> Result<(CatFloat, bool), ParseFloatError> {
+    let cat = classify_str(s)?;
+    Ok((cat, s.contains(['.', 'e', 'E'])))
+}
+
+/// Classifies an `f64` in a single pass over its bits.
+///
+/// The exponent is read once: values with an exponent of at least 52 are all integer,
+/// values below 1 are all fraction, and only the rest need their fractional bits masked off.
+/// This is what the `f64` impl of [`Category`] uses, and it always agrees with [`category_of`].
+///
+/// # Examples:
+/// ```rust
+/// # use floating_cat::*;
+/// assert_eq!(category_fast(1.5), CatFloat::IntegerAndFractionalPart(1.0, 0.5));
+/// assert_eq!(category_fast(1.5), category_of(1.5));
+/// ```
+pub const fn category_fast(x: f64) -> CatFloat {
+    use ieee754::{EXPONENT_BIAS, EXPONENT_MASK, MANTISSA_BITS, MANTISSA_MASK};
+
+    let bits = x.to_bits();
+    let biased_exponent = (bits >> MANTISSA_BITS) & EXPONENT_MASK;
+    let sign = if x.is_sign_negative() {
+        Sign::Negative
+    } else {
+        Sign::Positive
+    };
+
+    if biased_exponent == EXPONENT_MASK {
+        if bits & MANTISSA_MASK == 0 {
+            return CatFloat::Infinity(sign);
+        }
+        return nan_of_bits(bits);
+    }
+    if biased_exponent == 0 {
+        if bits & MANTISSA_MASK == 0 {
+            return CatFloat::Zero(sign);
+        }
+        return CatFloat::Subnormal(x);
+    }
+
+    let exponent = biased_exponent as i32 - EXPONENT_BIAS;
+    if exponent < 0 {
+        return CatFloat::FractionLike(x);
+    }
+    if exponent >= MANTISSA_BITS as i32 {
+        if is_huge(x) {
+            return CatFloat::HugeInteger(x);
+        }
+        return CatFloat::IntegerLike(x);
+    }
+
+    let fract_mask = (1u64 << (MANTISSA_BITS as i32 - exponent)) - 1;
+    if bits & fract_mask == 0 {
+        return CatFloat::IntegerLike(x);
+    }
+    let int_part = f64::from_bits(bits & !fract_mask);
+    CatFloat::IntegerAndFractionalPart(int_part, x - int_part)
+}
+
+/// Classifies an `f64` by computing every predicate up front, with no data-dependent branches
+/// until the variant is built.
+///
+/// [`category_fast`] branches on the exponent, which mispredicts when the input mixes
+/// categories unpredictably. Here every predicate, the sign and the [`CatFloat::HugeInteger`]
+/// cutoff included, comes from the sign, exponent and mantissa bits; the fractional-bit mask
+/// is clamped rather than branched on, the predicates are combined arithmetically into a
+/// variant index, and only the final `match` on that index remains. It always agrees with [`category_fast`]; which of the two is
+/// faster depends on the data, so measure with `cargo bench`.
+///
+/// # Examples:
+/// ```rust
+/// # use floating_cat::*;
+/// assert_eq!(category_branchless(1.5), CatFloat::IntegerAndFractionalPart(1.0, 0.5));
+/// assert_eq!(category_branchless(-0.25), category_fast(-0.25));
+/// ```
+pub fn category_branchless(x: f64) -> CatFloat {
+    use ieee754::{EXPONENT_BIAS, EXPONENT_MASK, MANTISSA_BITS, MANTISSA_MASK, SIGN_MASK};
+
+    let bits = x.to_bits();
+    let biased_exponent = (bits >> MANTISSA_BITS) & EXPONENT_MASK;
+    let exponent = biased_exponent as i32 - EXPONENT_BIAS;
+
+    // Bits below the binary point. Exponents under 0 shift by 63, leaving only the sign above
+    // the mask, so the integer part is a signed zero; exponents of 52 or more shift by 0.
+    let below_one = (exponent < 0) as i32;
+    let shift = ((MANTISSA_BITS as i32 - exponent).max(0) | (below_one * 63)).min(63);
+    let fract_mask = (1u64 << shift) - 1;
+    let int_bits = bits & !fract_mask;
+
+    let special = (biased_exponent == EXPONENT_MASK) as u8;
+    let tiny = (biased_exponent == 0) as u8;
+    let normal = 1 - special - tiny;
+    let mantissa_zero = (bits & MANTISSA_MASK == 0) as u8;
+    let fract_zero = (bits & fract_mask == 0) as u8;
+    let int_zero = (int_bits & !SIGN_MASK == 0) as u8;
+    // `HugeInteger` starts at 2^128, the first magnitude with an exponent of 128.
+    let huge = (exponent >= 128) as u8;
+
+    // The same numbering as `variant_index`; exactly one of the three terms is nonzero.
+    let index = special * (3 + mantissa_zero)
+        + tiny * (6 - mantissa_zero)
+        + normal * (fract_zero * huge * 7 + (1 - fract_zero) * (2 - int_zero));
+
+    let sign = [Sign::Positive, Sign::Negative][(bits >> 63) as usize];
+    let int_part = f64::from_bits(int_bits);
+    match index {
+        0 => CatFloat::IntegerLike(x),
+        1 => CatFloat::FractionLike(x),
+        2 => CatFloat::IntegerAndFractionalPart(int_part, x - int_part),
+        3 => nan_of_bits(bits),
+        4 => CatFloat::Infinity(sign),
+        5 => CatFloat::Zero(sign),
+        6 => CatFloat::Subnormal(x),
+        _ => CatFloat::HugeInteger(x),
+    }
+}
+
+/// Splits a duration in `f64` seconds into whole seconds and the subsecond remainder.
+///
+/// Returns [`CastError::OutOfRange`] for negative durations or ones too long for a `u64`
+/// of seconds, and [`CastError::WasNaN`] or [`CastError::WasInfinite`] for special values.
+///
+/// # Examples:
+/// ```rust
+/// # use floating_cat::*;
+/// assert_eq!(split_duration(1.5), Ok((1, 0.5)));
+/// assert_eq!(split_duration(-1.0), Err(CastError::OutOfRange));
+/// ```
+pub fn split_duration(secs: f64) -> Result<(u64, f64), CastError> {
+    // u64::MAX rounds up to 2^64, which is itself out of range.
+    const UPPER: f64 = u64::MAX as f64;
+
+    let (whole, fract) = match secs.category() {
+        CatFloat::Nan { .. } => return Err(CastError::WasNaN),
+        CatFloat::Infinity(..) => return Err(CastError::WasInfinite),
+        CatFloat::Zero(..) => return Ok((0, 0.0)),
+        CatFloat::IntegerLike(n) | CatFloat::HugeInteger(n) => (n, 0.0),
+        CatFloat::FractionLike(n) | CatFloat::Subnormal(n) => (0.0, n),
+        CatFloat::IntegerAndFractionalPart(int, fract) => (int, fract),
+    };
+
+    if secs < 0.0 || whole >= UPPER {
+        return Err(CastError::OutOfRange);
+    }
+    Ok((whole as u64, fract))
+}
+
+/// Classifies a raw IEEE-754 bit pattern, as if by `f64::from_bits(bits).category()`.
+///
+/// No arithmetic touches the value before it's classified, so signaling NaN patterns stay
+/// [`NanKind::Signaling`]. A NaN's kind comes from its quiet bit, and the bits below that
+/// are kept as its payload.
+///
+/// # Examples:
+/// ```rust
+/// # use floating_cat::*;
+/// assert_eq!(classify_bits(0x3ff8_0000_0000_0000), CatFloat::IntegerAndFractionalPart(1.0, 0.5));
+/// let sig = classify_bits(0x7ff0_0000_0000_0001);
+/// assert_eq!(sig, CatFloat::Nan { kind: NanKind::Signaling, payload: 1 });
+/// ```
+pub const fn classify_bits(bits: u64) -> CatFloat {
+    category_fast(f64::from_bits(bits))
+}
+
+/// Classifies `x`, but returns an error instead of [`CatFloat::Nan`] or [`CatFloat::Infinity`].
+///
+/// # Examples:
+/// ```rust
+/// # use floating_cat::*;
+/// assert_eq!(category_strict(1.5), Ok(CatFloat::IntegerAndFractionalPart(1.0, 0.5)));
+/// assert_eq!(category_strict(f64::NAN), Err(NonFinite::WasNaN));
+/// ```
+pub fn category_strict(x: f64) -> Result<CatFloat, NonFinite> {
+    match x.category() {
+        CatFloat::Nan { .. } => Err(NonFinite::WasNaN),
+        CatFloat::Infinity(..) => Err(NonFinite::WasInfinite),
+        cat => Ok(cat),
+    }
+}
+
+/// A [`CatFloat`] bundled with metadata read from the float's bits; see [`category_detailed`].
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct CatFloatDetailed {
+    pub category: CatFloat,
+
+    /// The sign bit, even for zeros and NaNs.
+    pub sign: Sign,
+
+    /// The exponent field minus the bias of 1023, so `1.5` gives `0`.
+    /// Subnormals and zeros give `-1023`; NaN and the infinities give `1024`.
+    pub exponent: i32,
+
+    pub is_subnormal: bool,
+
+    /// Whether [`CatFloat::try_as_i64`] succeeds.
+    pub fits_in_i64: bool,
+}
+
+/// Classifies `x` and gathers its sign, exponent and range details in one go.
+///
+/// # Examples:
+/// ```rust
+/// # use floating_cat::*;
+/// let detailed = category_detailed(-2.0);
+/// assert_eq!(detailed.category, CatFloat::IntegerLike(-2.0));
+/// assert_eq!(detailed.sign, Sign::Negative);
+/// assert_eq!(detailed.exponent, 1);
+/// assert!(detailed.fits_in_i64);
+/// ```
+pub fn category_detailed(x: f64) -> CatFloatDetailed {
+    let (negative, biased_exponent, _) = decompose_ieee754(x);
+    let category = x.category();
+
+    CatFloatDetailed {
+        category,
+        sign: if negative {
+            Sign::Negative
+        } else {
+            Sign::Positive
+        },
+        exponent: i32::from(biased_exponent) - ieee754::EXPONENT_BIAS,
+        is_subnormal: category.is_subnormal(),
+        fits_in_i64: category.try_as_i64().is_ok(),
+    }
+}
+
+/// Classifies `x * 2^scale_pow2`, for fixed-point values with `scale_pow2` fractional bits.
+///
+/// The scaling adjusts the exponent directly, so it's exact unless the result overflows to
+/// infinity or drops into the subnormal range.
+///
+/// # Examples:
+/// ```rust
+/// # use floating_cat::*;
+/// assert_eq!(category_scaled(0.25, 2), CatFloat::IntegerLike(1.0));
+/// assert_eq!(category_scaled(3.0, -1), CatFloat::IntegerAndFractionalPart(1.0, 0.5));
+/// ```
+pub fn category_scaled(x: f64, scale_pow2: i32) -> CatFloat {
+    ieee754::scale_pow2(x, scale_pow2).category()
+}
+
+/// How one classification differs from another; see [`category_diff`].
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct CategoryDiff {
+    /// Whether the two are different variants, as by [`CatFloat::variant_index`].
+    pub variant_changed: bool,
+
+    /// `b`'s integer part minus `a`'s, or `None` if either is [`CatFloat::Nan`] or
+    /// [`CatFloat::Infinity`].
+    pub integer_delta: Option<f64>,
+
+    /// `b`'s fractional part minus `a`'s, or `None` if either is [`CatFloat::Nan`] or
+    /// [`CatFloat::Infinity`].
+    pub fraction_delta: Option<f64>,
+}
+
+/// Compares two classifications, part by part.
+///
+/// # Examples:
+/// ```rust
+/// # use floating_cat::*;
+/// let diff = category_diff(
+///     CatFloat::IntegerAndFractionalPart(1.0, 0.5),
+///     CatFloat::IntegerAndFractionalPart(1.0, 0.75),
+/// );
+/// assert!(!diff.variant_changed);
+/// assert_eq!(diff.integer_delta, Some(0.0));
+/// assert_eq!(diff.fraction_delta, Some(0.25));
+/// ```
+pub fn category_diff(a: CatFloat, b: CatFloat) -> CategoryDiff {
+    let delta = |a: Option<f64>, b: Option<f64>| Some(b? - a?);
+
+    CategoryDiff {
+        variant_changed: a.variant_index() != b.variant_index(),
+        integer_delta: delta(a.integer_part(), b.integer_part()),
+        fraction_delta: delta(a.fractional_part(), b.fractional_part()),
+    }
+}
+
+/// Classifies every integer in `0..256`, so entry `i` is `(i as f64).category()`.
+///
+/// That makes entry `0` [`CatFloat::Zero`], and every other entry [`CatFloat::IntegerLike`].
+/// This is a `const fn`, so the table can be built at compile time.
+///
+/// # Examples:
+/// ```rust
+/// # use floating_cat::*;
+/// const TABLE: [CatFloat; 256] = integer_category_table();
+/// assert_eq!(TABLE[42], CatFloat::IntegerLike(42.0));
+/// ```
+pub const fn integer_category_table() -> [CatFloat; 256] {
+    let mut table = [CatFloat::EMPTY; 256];
+    let mut i = 0;
+    while i < table.len() {
+        table[i] = category_fast(i as f64);
+        i += 1;
+    }
+    table
+}
+
+/// [`integer_category_table`], built at compile time.
+static SMALL_INTEGERS: [CatFloat; 256] = integer_category_table();
+
+/// Classifies `n` by looking it up in a precomputed table.
+///
+/// # Examples:
+/// ```rust
+/// # use floating_cat::*;
+/// assert_eq!(classify_small_u8(42), CatFloat::IntegerLike(42.0));
+/// ```
+pub fn classify_small_u8(n: u8) -> CatFloat {
+    SMALL_INTEGERS[usize::from(n)]
+}
+
+/// The bits `==` and `hash` compare a payload by: `-0.0` becomes `0.0`, and every NaN
+/// becomes `f64::NAN`.
+fn normalized_bits(n: f64) -> u64 {
+    let n = if n.is_nan() { f64::NAN } else { n + 0.0 };
+    n.to_bits()
+}
+
+/// The exact continued-fraction terms of `x`, or `None` if it isn't finite or its integer
+/// part doesn't fit in an `i128`.
+///
+/// Every finite `f64` is exactly `m * 2^e`, so this is Euclid's algorithm on that numerator
+/// and denominator, ending when the remainder is zero. Denominators beyond `2^126` are
+/// replaced by `2^126` with a numerator of `±1`: the terms keep the same leading values, and
+/// the term where they'd differ is already far too large for an `i64` or `u64`.
+#[cfg(feature = "alloc")]
+pub(crate) fn exact_continued_fraction(x: f64) -> Option<impl Iterator<Item = i128>> {
+    use ieee754::{EXPONENT_BIAS, MANTISSA_BITS};
+
+    if !x.is_finite() {
+        return None;
+    }
+    let (negative, biased_exponent, mantissa) = decompose_ieee754(x);
+    let (mut m, mut e) = match biased_exponent {
+        0 => (mantissa, 1 - EXPONENT_BIAS - MANTISSA_BITS as i32),
+        b => (
+            mantissa | 1 << MANTISSA_BITS,
+            i32::from(b) - EXPONENT_BIAS - MANTISSA_BITS as i32,
+        ),
+    };
+    if m != 0 {
+        // Reduce the fraction, so `e >= 0` means the value is a whole number.
+        e += m.trailing_zeros() as i32;
+        m >>= m.trailing_zeros();
+    }
+    let m = if negative {
+        -i128::from(m)
+    } else {
+        i128::from(m)
+    };
+
+    let pow2 = |k: u32| 1i128.checked_shl(k).filter(|n| *n > 0);
+    let (p, q) = if e >= 0 {
+        (m.checked_mul(pow2(e.unsigned_abs())?)?, 1)
+    } else {
+        match pow2(e.unsigned_abs()) {
+            Some(q) => (m, q),
+            None => (m.signum(), 1 << 126),
+        }
+    };
+
+    let mut state = Some((p, q));
+    Some(core::iter::from_fn(move || {
+        let (p, q) = state?;
+        let r = p.rem_euclid(q);
+        state = (r != 0).then_some((q, r));
+        Some(p.div_euclid(q))
+    }))
+}
+
+/// Reads the quiet bit and payload of any `FloatCore` NaN.
+///
+/// `integer_decode` hands back the mantissa with its implicit leading bit set,
+/// so the quiet bit is the one just below the highest set bit.
+fn nan_parts_of<F: FloatCore>(x: F) -> (NanKind, u64) {
+    let (mantissa, _, _) = x.integer_decode();
+    let implicit_bit = u64::BITS - 1 - mantissa.leading_zeros();
+    let quiet_bit = 1 << (implicit_bit - 1);
+    let payload = mantissa & (quiet_bit - 1);
+    if mantissa & quiet_bit == 0 {
+        (NanKind::Signaling, payload)
+    } else {
+        (NanKind::Quiet, payload)
+    }
+}
+
+/// Classifies the bits of an `f64` NaN.
+const fn nan_of_bits(bits: u64) -> CatFloat {
+    let kind = if bits & ieee754::QUIET_NAN_BIT == 0 {
+        NanKind::Signaling
+    } else {
+        NanKind::Quiet
+    };
+    CatFloat::Nan {
+        kind,
+        payload: bits & ieee754::NAN_PAYLOAD_MASK,
+    }
+}
+
+/// A signaling NaN: all exponent bits set, quiet bit clear, and a nonzero payload.
+const SIGNALING_NAN_BITS: u64 = 0x7ff4_0000_0000_0000;
+
+/// Whether an integer-valued `n` is too big for every integer type.
+///
+/// `u128::MAX as f64` rounds up to 2^128, which `u128` can't hold either, so the boundary is inclusive.
+const fn is_huge(n: f64) -> bool {
+    n >= HUGE_INTEGER_LIMIT || n <= -HUGE_INTEGER_LIMIT
+}
+
+/// The smallest magnitude classified as [`CatFloat::HugeInteger`].
+pub(crate) const HUGE_INTEGER_LIMIT: f64 = u128::MAX as f64;
+
+/// `f64::trunc` for finite floats, by clearing the mantissa bits below the binary point.
+const fn const_trunc(x: f64) -> f64 {
+    use ieee754::{EXPONENT_BIAS, MANTISSA_BITS, SIGN_MASK};
+
+    let bits = x.to_bits();
+    let (_, biased_exponent, _) = decompose_ieee754(x);
+    let exponent = biased_exponent as i32 - EXPONENT_BIAS;
+
+    if exponent < 0 {
+        // |x| < 1, so only the sign survives.
+        f64::from_bits(bits & SIGN_MASK)
+    } else if exponent >= MANTISSA_BITS as i32 {
+        // Every mantissa bit is above the binary point already.
+        x
+    } else {
+        let fract_mask = (1u64 << (MANTISSA_BITS as i32 - exponent)) - 1;
+        f64::from_bits(bits & !fract_mask)
+    }
+}
+
+#[test]
+fn trait_works() {
+    use crate::*;
+
+    let f: f64 = 1.5;
+    assert_eq!(f.category(), CatFloat::IntegerAndFractionalPart(1.0, 0.5));
+
+    let f: f64 = 1.0;
+    assert_eq!(f.category(), CatFloat::IntegerLike(1.0));
+
+    let f: f64 = 0.2;
+    assert_eq!(f.category(), CatFloat::FractionLike(0.2));
+
+    let f: f64 = f64::INFINITY;
+    assert_eq!(f.category(), CatFloat::Infinity(Sign::Positive));
+
+    let f: f64 = f64::NEG_INFINITY;
+    assert_eq!(f.category(), CatFloat::Infinity(Sign::Negative));
+    assert!(f.category().is_infinity());
+    assert!(f.category().is_negative_infinity());
+    assert!(!f.category().is_positive_infinity());
+
+    let f: f64 = f64::NAN;
+    assert_eq!(
+        f.category(),
+        CatFloat::Nan {
+            kind: NanKind::Quiet,
+            payload: 0
+        }
+    );
+
+    let f: f64 = 0.0;
+    assert_eq!(f.category(), CatFloat::Zero(Sign::Positive));
+    assert!(f.category().is_zero());
+    assert!(!f.category().is_negative_zero());
+
+    let f: f64 = -0.0;
+    assert_eq!(f.category(), CatFloat::Zero(Sign::Negative));
+    assert!(f.category().is_negative_zero());
+
+    let f: f64 = -100.0;
+    assert_eq!(f.category(), CatFloat::IntegerLike(-100.0));
+    assert!(!f.category().is_zero());
+
+    let f: f64 = f64::MIN_POSITIVE / 2.0;
+    assert_eq!(f.category(), CatFloat::Subnormal(f));
+    assert!(f.category().is_subnormal());
+
+    let f: f64 = f64::MIN_POSITIVE;
+    assert_eq!(f.category(), CatFloat::FractionLike(f));
+    assert!(!0.2f64.category().is_subnormal());
+}
+
+#[test]
+fn trait_works_f32() {
+    use crate::*;
+
+    let f: f32 = 1.5;
+    assert_eq!(f.category(), CatF32::IntegerAndFractionalPart(1.0, 0.5));
+
+    let f: f32 = 1.0;
+    assert_eq!(f.category(), CatF32::IntegerLike(1.0));
+
+    let f: f32 = 0.2;
+    assert_eq!(f.category(), CatF32::FractionLike(0.2));
+
+    let f: f32 = f32::INFINITY;
+    assert_eq!(f.category(), CatF32::Infinity(Sign::Positive));
+
+    let f: f32 = f32::NEG_INFINITY;
+    assert_eq!(f.category(), CatF32::Infinity(Sign::Negative));
+
+    let f: f32 = f32::NAN;
+    assert_eq!(f.category(), CatF32::Nan(NanKind::Quiet));
+
+    let f: f32 = -0.0;
+    assert_eq!(f.category(), CatF32::Zero(Sign::Negative));
+
+    let f: f32 = f32::MIN_POSITIVE / 2.0;
+    assert_eq!(f.category(), CatF32::Subnormal(f));
+
+    let f: f32 = f32::MAX;
+    assert_eq!(f.category(), CatF32::IntegerLike(f32::MAX));
+}
+
+#[test]
+fn to_f64_round_trips() {
+    use crate::*;
+
+    let mut bits: u64 = 0;
+    loop {
+        let f = f64::from_bits(bits);
+        let back = f.category().to_f64();
+        if f.is_nan() {
+            assert!(back.is_nan());
+        } else {
+            assert_eq!(back.to_bits(), f.to_bits(), "{f} did not round-trip");
+        }
+
+        match bits.checked_add(0x0000_1234_5678_9abd) {
+            Some(next) => bits = next,
+            None => break,
+        }
+    }
+
+    for f in [
+        0.0,
+        -0.0,
+        1.5,
+        -1.5,
+        0.2,
+        -100.0,
+        f64::MAX,
+        f64::MIN,
+        5e-324,
+    ] {
+        assert_eq!(f.category().to_f64().to_bits(), f.to_bits());
+    }
+    assert_eq!(f64::INFINITY.category().to_f64(), f64::INFINITY);
+    assert_eq!(f64::NEG_INFINITY.category().to_f64(), f64::NEG_INFINITY);
+}
+
+#[test]
+fn try_as_i64_works() {
+    use crate::*;
+
+    assert_eq!(1.0f64.category().try_as_i64(), Ok(1));
+    assert_eq!((-100.0f64).category().try_as_i64(), Ok(-100));
+    assert_eq!(0.0f64.category().try_as_i64(), Ok(0));
+    assert_eq!((i64::MIN as f64).category().try_as_i64(), Ok(i64::MIN));
+    assert_eq!(
+        (i64::MAX as f64).category().try_as_i64(),
+        Err(CastError::OutOfRange)
+    );
+    assert_eq!(1.5f64.category().try_as_i64(), Err(CastError::NotInteger));
+    assert_eq!(0.5f64.category().try_as_i64(), Err(CastError::NotInteger));
+    assert_eq!(
+        f64::INFINITY.category().try_as_i64(),
+        Err(CastError::WasInfinite)
+    );
+    assert_eq!(f64::NAN.category().try_as_i64(), Err(CastError::WasNaN));
+}
+
+#[test]
+fn display_works() {
+    use crate::*;
+    use std::string::ToString;
+
+    assert_eq!(CatFloat::IntegerLike(1.0).to_string(), "integer-like (1)");
+    assert_eq!(
+        CatFloat::FractionLike(0.5).to_string(),
+        "fraction-like (0.5)"
+    );
+    assert_eq!(
+        CatFloat::IntegerAndFractionalPart(1.0, 0.5).to_string(),
+        "1 + 0.5"
+    );
+    assert_eq!(
+        CatFloat::Nan {
+            kind: NanKind::Quiet,
+            payload: 0
+        }
+        .to_string(),
+        "NaN"
+    );
+    assert_eq!(
+        CatFloat::Nan {
+            kind: NanKind::Signaling,
+            payload: 0
+        }
+        .to_string(),
+        "signaling NaN"
+    );
+    assert_eq!(CatFloat::Infinity(Sign::Positive).to_string(), "infinity");
+    assert_eq!(CatFloat::Infinity(Sign::Negative).to_string(), "-infinity");
+    assert_eq!(CatFloat::Zero(Sign::Positive).to_string(), "zero");
+    assert_eq!(CatFloat::Zero(Sign::Negative).to_string(), "-zero");
+    assert_eq!(
+        CatFloat::Subnormal(5e-324).to_string(),
+        "subnormal (5e-324)"
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_round_trips() {
+    use crate::*;
+
+    let c = CatFloat::IntegerAndFractionalPart(1.0, 0.5);
+    let json = serde_json::to_string(&c).unwrap();
+    assert_eq!(json, r#"{"IntegerAndFractionalPart":[1.0,0.5]}"#);
+    assert_eq!(serde_json::from_str::<CatFloat>(&json).unwrap(), c);
+
+    let json = serde_json::to_string(&CatFloat::Nan {
+        kind: NanKind::Quiet,
+        payload: 0,
+    })
+    .unwrap();
+    assert_eq!(json, r#"{"Nan":{"kind":"Quiet","payload":0}}"#);
+    assert!(serde_json::from_str::<CatFloat>(&json).unwrap().is_nan());
+
+    let c = CatFloat::Infinity(Sign::Negative);
+    let json = serde_json::to_string(&c).unwrap();
+    assert_eq!(json, r#"{"Infinity":"Negative"}"#);
+    assert_eq!(serde_json::from_str::<CatFloat>(&json).unwrap(), c);
+}
+
+#[cfg(not(feature = "std"))]
+#[test]
+fn no_std_works() {
+    use crate::*;
+
+    assert_eq!(
+        1.5f64.category(),
+        CatFloat::IntegerAndFractionalPart(1.0, 0.5)
+    );
+    assert_eq!((-0.5f64).category(), CatFloat::FractionLike(-0.5));
+    assert_eq!(2.0f32.category(), CatF32::IntegerLike(2.0));
+}
+
+#[test]
+fn category_of_works() {
+    use crate::*;
+
+    assert_eq!(
+        category_of(1.5f32),
+        CatFloat::IntegerAndFractionalPart(1.0, 0.5)
+    );
+    assert_eq!(category_of(0.25f32), CatFloat::FractionLike(0.25));
+    assert_eq!(
+        category_of(f32::NAN),
+        CatFloat::Nan {
+            kind: NanKind::Quiet,
+            payload: 0
+        }
+    );
+    assert_eq!(
+        category_of(f32::from_bits(0x7fa0_0000)),
+        CatFloat::Nan {
+            kind: NanKind::Signaling,
+            payload: 0x20_0000
+        }
+    );
+    assert_eq!(category_of(1.5f32), 1.5f64.category());
+}
+
+#[test]
+fn fp_category_matches_classify() {
+    use crate::*;
+
+    for f in [
+        1.5f64,
+        -1.0,
+        0.2,
+        0.0,
+        -0.0,
+        5e-324,
+        f64::MIN_POSITIVE,
+        f64::MAX,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::NAN,
+    ] {
+        assert_eq!(f.category().fp_category(), f.classify(), "{f}");
+    }
+}
+
+#[test]
+fn sign_works() {
+    use crate::*;
+
+    assert_eq!((-1.5f64).category().sign(), Some(Sign::Negative));
+    assert_eq!(1.5f64.category().sign(), Some(Sign::Positive));
+    assert_eq!((-0.5f64).category().sign(), Some(Sign::Negative));
+    assert_eq!((-3.0f64).category().sign(), Some(Sign::Negative));
+    assert_eq!((-0.0f64).category().sign(), Some(Sign::Negative));
+    assert_eq!(f64::NEG_INFINITY.category().sign(), Some(Sign::Negative));
+    assert_eq!(f64::NAN.category().sign(), None);
+}
+
+#[test]
+fn classify_f64_is_const() {
+    use crate::*;
+
+    const MIXED: CatFloat = classify_f64(1.5);
+    const INF: CatFloat = classify_f64(f64::NEG_INFINITY);
+    assert_eq!(MIXED, CatFloat::IntegerAndFractionalPart(1.0, 0.5));
+    assert_eq!(INF, CatFloat::Infinity(Sign::Negative));
+
+    let mut bits: u64 = 0;
+    loop {
+        let f = f64::from_bits(bits);
+        let (a, b) = (classify_f64(f), f.category());
+        if f.is_nan() {
+            assert!(a.is_nan());
+        } else {
+            assert_eq!(a, b, "{f:e}");
+        }
+
+        match bits.checked_add(0x0000_1234_5678_9abd) {
+            Some(next) => bits = next,
+            None => break,
+        }
+    }
+}
+
+#[test]
+fn huge_integers_are_separate() {
+    use crate::*;
+
+    assert_eq!(1e300f64.category(), CatFloat::HugeInteger(1e300));
+    assert_eq!((-1e300f64).category(), CatFloat::HugeInteger(-1e300));
+    assert!(f64::MAX.category().is_huge_integer());
+    assert!((u128::MAX as f64).category().is_huge_integer());
+    assert_eq!(
+        (u64::MAX as f64).category(),
+        CatFloat::IntegerLike(u64::MAX as f64)
+    );
+    assert_eq!(1.0f64.category(), CatFloat::IntegerLike(1.0));
+    assert!(!1.0f64.category().is_huge_integer());
+    assert_eq!(classify_f64(1e300), CatFloat::HugeInteger(1e300));
+}
+
+#[test]
+fn total_cmp_sorts_by_value() {
+    use crate::*;
+
+    let mut cats = std::vec![
+        f64::NAN.category(),
+        1.5f64.category(),
+        f64::INFINITY.category(),
+        (-0.5f64).category(),
+        0.0f64.category(),
+        f64::NEG_INFINITY.category(),
+        2.0f64.category(),
+    ];
+    cats.sort_by(CatFloat::total_cmp);
+
+    assert_eq!(cats[0], CatFloat::Infinity(Sign::Negative));
+    assert_eq!(cats[1], CatFloat::FractionLike(-0.5));
+    assert_eq!(cats[2], CatFloat::Zero(Sign::Positive));
+    assert_eq!(cats[3], CatFloat::IntegerAndFractionalPart(1.0, 0.5));
+    assert_eq!(cats[4], CatFloat::IntegerLike(2.0));
+    assert_eq!(cats[5], CatFloat::Infinity(Sign::Positive));
+    assert!(cats[6].is_nan());
+}
+
+#[test]
+fn category_with_epsilon_snaps() {
+    use crate::*;
+
+    assert_eq!(
+        category_with_epsilon(2.9999999996, 1e-6),
+        CatFloat::IntegerLike(3.0)
+    );
+    assert_eq!(
+        category_with_epsilon(-2.9999999996, 1e-6),
+        CatFloat::IntegerLike(-3.0)
+    );
+    assert_eq!(
+        category_with_epsilon(3.0000000004, 1e-6),
+        CatFloat::IntegerLike(3.0)
+    );
+    assert_eq!(
+        category_with_epsilon(-1e-9, 1e-6),
+        CatFloat::Zero(Sign::Negative)
+    );
+    assert_eq!(
+        category_with_epsilon(0.2, 1e-12),
+        CatFloat::FractionLike(0.2)
+    );
+    assert_eq!(
+        category_with_epsilon(f64::INFINITY, 1.0),
+        CatFloat::Infinity(Sign::Positive)
+    );
+    assert!(category_with_epsilon(f64::NAN, 1.0).is_nan());
+}
+
+#[test]
+fn hash_dedups() {
+    use crate::*;
+    use std::collections::HashSet;
+
+    let set: HashSet<CatFloat> = [1.5f64, 1.5, 0.5, 0.0, -0.0, f64::NAN, -f64::NAN, 2.0]
+        .iter()
+        .map(Category::category)
+        .collect();
+
+    assert_eq!(set.len(), 6);
+    assert!(set.contains(&CatFloat::Nan {
+        kind: NanKind::Quiet,
+        payload: 0
+    }));
+    assert!(set.contains(&CatFloat::Zero(Sign::Positive)));
+    assert!(set.contains(&CatFloat::Zero(Sign::Negative)));
+    assert!(set.contains(&CatFloat::IntegerAndFractionalPart(1.0, 0.5)));
+
+    let set: HashSet<CatFloat> = [CatFloat::FractionLike(0.0), CatFloat::FractionLike(-0.0)]
+        .into_iter()
+        .collect();
+    assert_eq!(set.len(), 1);
+}
+
+#[test]
+fn category_floor_based_works() {
+    use crate::*;
+
+    assert_eq!(
+        (-1.5f64).category(),
+        CatFloat::IntegerAndFractionalPart(-1.0, -0.5)
+    );
+    assert_eq!(
+        category_floor_based(-1.5),
+        CatFloat::IntegerAndFractionalPart(-2.0, 0.5)
+    );
+    assert_eq!(
+        category_floor_based(-0.25),
+        CatFloat::IntegerAndFractionalPart(-1.0, 0.75)
+    );
+    assert_eq!(
+        category_floor_based(1.5),
+        CatFloat::IntegerAndFractionalPart(1.0, 0.5)
+    );
+    assert_eq!(category_floor_based(0.25), CatFloat::FractionLike(0.25));
+    assert_eq!(category_floor_based(-3.0), CatFloat::IntegerLike(-3.0));
+    assert_eq!(
+        category_floor_based(-5e-324),
+        CatFloat::Zero(Sign::Negative)
+    );
+    assert_eq!(
+        category_floor_based(-f64::MIN_POSITIVE / 2.0),
+        CatFloat::Zero(Sign::Negative)
+    );
+    assert_eq!(category_floor_based(5e-324), CatFloat::Subnormal(5e-324));
+    assert_eq!(category_floor_based(-1e-20), CatFloat::Zero(Sign::Negative));
+    assert!(category_floor_based(f64::NAN).is_nan());
+}
+
+#[test]
+fn nan_kinds_are_distinguished() {
+    use crate::*;
+
+    let quiet = f64::NAN;
+    let signaling = f64::from_bits(0x7ff0_0000_0000_0001);
+    assert!(signaling.is_nan());
+
+    assert_eq!(
+        quiet.category(),
+        CatFloat::Nan {
+            kind: NanKind::Quiet,
+            payload: 0
+        }
+    );
+    assert_eq!(
+        signaling.category(),
+        CatFloat::Nan {
+            kind: NanKind::Signaling,
+            payload: 1
+        }
+    );
+    assert!(signaling.category().is_nan());
+    assert!(signaling.category().is_signaling_nan());
+    assert!(!quiet.category().is_signaling_nan());
+    assert_eq!(
+        classify_f64(signaling),
+        CatFloat::Nan {
+            kind: NanKind::Signaling,
+            payload: 1
+        }
+    );
+    assert!(CatFloat::Nan {
+        kind: NanKind::Signaling,
+        payload: 0
+    }
+    .to_f64()
+    .category()
+    .is_signaling_nan());
+
+    let signaling = f32::from_bits(0x7f80_0001);
+    assert_eq!(signaling.category(), CatF32::Nan(NanKind::Signaling));
+}
+
+#[test]
+fn map_parts_keeps_variant() {
+    use crate::*;
+
+    let doubled = CatFloat::IntegerAndFractionalPart(1.0, 0.5).map_parts(|n| n * 2.0);
+    assert_eq!(doubled, CatFloat::IntegerAndFractionalPart(2.0, 1.0));
+    assert_eq!(doubled.to_f64().category(), CatFloat::IntegerLike(3.0));
+
+    assert_eq!(
+        CatFloat::FractionLike(0.25).map_parts(|n| -n),
+        CatFloat::FractionLike(-0.25)
+    );
+    assert_eq!(
+        CatFloat::Infinity(Sign::Positive).map_parts(|_| 0.0),
+        CatFloat::Infinity(Sign::Positive)
+    );
+    assert!(CatFloat::Nan {
+        kind: NanKind::Quiet,
+        payload: 0
+    }
+    .map_parts(|_| 0.0)
+    .is_nan());
+}
+
+#[test]
+fn conversions_work() {
+    use crate::*;
+
+    assert_eq!(
+        CatFloat::from(1.5),
+        CatFloat::IntegerAndFractionalPart(1.0, 0.5)
+    );
+    assert_eq!(CatFloat::from(-0.0), CatFloat::Zero(Sign::Negative));
+
+    assert_eq!(f64::try_from(CatFloat::from(1.5)), Ok(1.5));
+    assert_eq!(
+        f64::try_from(CatFloat::Infinity(Sign::Negative)),
+        Ok(f64::NEG_INFINITY)
+    );
+    assert_eq!(
+        f64::try_from(CatFloat::from(f64::NAN)),
+        Err(CastError::WasNaN)
+    );
+}
+
+#[test]
+fn part_accessors_work() {
+    use crate::*;
+
+    let c = 3.0f64.category();
+    assert_eq!(
+        (c.integer_part(), c.fractional_part()),
+        (Some(3.0), Some(0.0))
+    );
+
+    let c = (-0.5f64).category();
+    assert_eq!(
+        (c.integer_part(), c.fractional_part()),
+        (Some(0.0), Some(-0.5))
+    );
+
+    let c = 1.5f64.category();
+    assert_eq!(
+        (c.integer_part(), c.fractional_part()),
+        (Some(1.0), Some(0.5))
+    );
+
+    let c = 0.0f64.category();
+    assert_eq!(
+        (c.integer_part(), c.fractional_part()),
+        (Some(0.0), Some(0.0))
+    );
+
+    let c = 5e-324f64.category();
+    assert_eq!(
+        (c.integer_part(), c.fractional_part()),
+        (Some(0.0), Some(5e-324))
+    );
+
+    let c = 1e300f64.category();
+    assert_eq!(
+        (c.integer_part(), c.fractional_part()),
+        (Some(1e300), Some(0.0))
+    );
+
+    for f in [f64::NAN, f64::INFINITY] {
+        let c = f.category();
+        assert_eq!((c.integer_part(), c.fractional_part()), (None, None));
+    }
+
+    for f in [3.0f64, -0.5, -1.5, -0.0, 5e-324, 1e300] {
+        let c = f.category();
+        assert_eq!(c.integer_part().unwrap().to_bits(), f.trunc().to_bits());
+        assert_eq!(c.fractional_part().unwrap().to_bits(), f.fract().to_bits());
+    }
+}
+
+#[test]
+fn classify_str_works() {
+    use crate::*;
+
+    assert_eq!(
+        classify_str("1.5"),
+        Ok(CatFloat::IntegerAndFractionalPart(1.0, 0.5))
+    );
+    assert_eq!(classify_str("inf"), Ok(CatFloat::Infinity(Sign::Positive)));
+    assert_eq!(
+        classify_str("-infinity"),
+        Ok(CatFloat::Infinity(Sign::Negative))
+    );
+    assert!(classify_str("nan").unwrap().is_nan());
+    assert!(classify_str("1.5.2").is_err());
+    assert!(classify_str("").is_err());
+}
+
+#[test]
+fn approx_eq_works() {
+    use crate::*;
+
+    assert!(CatFloat::IntegerAndFractionalPart(1.0, 0.5000001)
+        .approx_eq(&CatFloat::IntegerAndFractionalPart(1.0, 0.5), 1e-3));
+    assert!(!CatFloat::IntegerAndFractionalPart(1.0, 0.6)
+        .approx_eq(&CatFloat::IntegerAndFractionalPart(1.0, 0.5), 1e-3));
+    assert!(!CatFloat::IntegerLike(1.0).approx_eq(&CatFloat::FractionLike(1.0), 1.0));
+    assert!(f64::NAN.category().approx_eq(&f64::NAN.category(), 0.0));
+    assert!(!f64::NAN
+        .category()
+        .approx_eq(&1.0f64.category(), f64::INFINITY));
+    assert!(!CatFloat::Zero(Sign::Positive).approx_eq(&CatFloat::Zero(Sign::Negative), 1.0));
+}
+
+#[test]
+fn finite_and_integer_predicates_work() {
+    use crate::*;
+
+    assert!(1.0f64.category().is_finite());
+    assert!(1e300f64.category().is_finite());
+    assert!(0.5f64.category().is_finite());
+    assert!(!f64::NAN.category().is_finite());
+    assert!(!f64::NEG_INFINITY.category().is_finite());
+
+    assert!(1.0f64.category().is_representable_as_integer());
+    assert!(0.0f64.category().is_representable_as_integer());
+    assert!((i128::MIN as f64).category().is_representable_as_integer());
+    assert!(!(-(i128::MIN as f64))
+        .category()
+        .is_representable_as_integer());
+    assert!(!1e300f64.category().is_representable_as_integer());
+    assert!(!0.5f64.category().is_representable_as_integer());
+    assert!(!f64::NAN.category().is_representable_as_integer());
+}
+
+#[test]
+fn round_to_category_works() {
+    use crate::*;
+
+    assert_eq!(0.5f64.category().round_to_category(), 0.0);
+    assert!((-0.5f64).category().round_to_category().is_sign_negative());
+    assert_eq!(1.5f64.category().round_to_category(), 2.0);
+    assert_eq!(2.5f64.category().round_to_category(), 3.0);
+    assert_eq!((-2.5f64).category().round_to_category(), -3.0);
+    assert_eq!(1.25f64.category().round_to_category(), 1.0);
+    assert_eq!(2.0f64.category().round_to_category(), 2.0);
+    assert_eq!(1e300f64.category().round_to_category(), 1e300);
+    assert_eq!(
+        f64::NEG_INFINITY.category().round_to_category(),
+        f64::NEG_INFINITY
+    );
+    assert!(f64::NAN.category().round_to_category().is_nan());
+}
+
+#[test]
+fn wrapper_category_works() {
+    use crate::*;
+
+    assert_eq!(
+        Some(1.5).category(),
+        Some(CatFloat::IntegerAndFractionalPart(1.0, 0.5))
+    );
+    assert_eq!(None::<f64>.category(), None);
+
+    let ok: Result<f64, &str> = Ok(0.5);
+    assert_eq!(ok.category(), Ok(CatFloat::FractionLike(0.5)));
+    let err: Result<f64, &str> = Err("missing");
+    assert_eq!(err.category(), Err("missing"));
+}
+
+#[test]
+fn category_fast_matches_category_of() {
+    use crate::*;
+
+    let same = |a: CatFloat, b: CatFloat| match (a, b) {
+        (
+            CatFloat::IntegerAndFractionalPart(ai, af),
+            CatFloat::IntegerAndFractionalPart(bi, bf),
+        ) => ai.to_bits() == bi.to_bits() && af.to_bits() == bf.to_bits(),
+        _ => a == b && a.to_f64().to_bits() == b.to_f64().to_bits(),
+    };
+
+    let specials = [
+        0.0,
+        -0.0,
+        1.0,
+        -1.0,
+        0.5,
+        -1.5,
+        4503599627370495.5,
+        4503599627370496.0,
+        9007199254740993.0,
+        HUGE_INTEGER_LIMIT,
+        -HUGE_INTEGER_LIMIT,
+        f64::MIN_POSITIVE,
+        5e-324,
+        f64::MAX,
+        f64::MIN,
+        f64::EPSILON,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::NAN,
+        f64::from_bits(SIGNALING_NAN_BITS),
+    ];
+    for x in specials {
+        assert!(same(category_fast(x), category_of(x)), "{x:?}");
+    }
+
+    // A stride coprime to 2^64 walks sign, exponent and mantissa bits alike.
+    let mut bits = 0u64;
+    for _ in 0..1_000_000 {
+        bits = bits.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let x = f64::from_bits(bits);
+        assert!(same(category_fast(x), category_of(x)), "{bits:#x}");
+    }
+}
+
+#[test]
+fn classify_all_matches_manual() {
+    use crate::*;
+
+    let xs = [1.0f64, 0.5, -2.25];
+    let cats = classify_all!(xs[0], xs[1] + 0.0, xs[2], f64::NAN, 3.0,);
+
+    assert_eq!(cats.len(), 5);
+    for (x, cat) in xs.iter().zip(&cats) {
+        assert_eq!(*cat, x.category());
+    }
+    assert!(cats[3].is_nan());
+    assert_eq!(cats[4], 3.0f64.category());
+
+    let none: [CatFloat; 0] = classify_all!();
+    assert!(none.is_empty());
+}
+
+#[test]
+fn parts_are_normalized() {
+    use crate::*;
+
+    assert_eq!(3.0f64.category().parts(), (3.0, 0.0, None));
+    assert_eq!(0.5f64.category().parts(), (0.0, 0.5, None));
+    assert_eq!(1.5f64.category().parts(), (1.0, 0.5, None));
+    assert_eq!(0.0f64.category().parts(), (0.0, 0.0, None));
+    assert_eq!(5e-324f64.category().parts(), (0.0, 5e-324, None));
+    assert_eq!(1e300f64.category().parts(), (1e300, 0.0, None));
+    assert_eq!(f64::NAN.category().parts(), (0.0, 0.0, Some(Special::Nan)));
+    assert_eq!(
+        f64::NEG_INFINITY.category().parts(),
+        (0.0, 0.0, Some(Special::Infinity))
+    );
+}
+
+#[test]
+fn fractional_fraction_works() {
+    use crate::*;
+
+    assert_eq!(1.25f64.category().fractional_fraction(), Some(0.25));
+    assert_eq!((-1.25f64).category().fractional_fraction(), Some(0.25));
+    assert_eq!((-0.75f64).category().fractional_fraction(), Some(0.75));
+    assert_eq!(3.0f64.category().fractional_fraction(), Some(0.0));
+    assert_eq!((-0.0f64).category().fractional_fraction(), Some(0.0));
+    assert_eq!(f64::NAN.category().fractional_fraction(), None);
+    assert_eq!(f64::INFINITY.category().fractional_fraction(), None);
+}
+
+#[test]
+fn powers_of_two_are_detected() {
+    use crate::*;
+
+    assert!(0.25f64.category().is_power_of_two());
+    assert!(0.5f64.category().is_power_of_two());
+    assert!(1.0f64.category().is_power_of_two());
+    assert!(2.0f64.category().is_power_of_two());
+    assert!(1024.0f64.category().is_power_of_two());
+    assert!(HUGE_INTEGER_LIMIT.category().is_power_of_two());
+    assert!(!3.0f64.category().is_power_of_two());
+    assert!(!1.5f64.category().is_power_of_two());
+    assert!(!(-2.0f64).category().is_power_of_two());
+    assert!(!0.0f64.category().is_power_of_two());
+    assert!(!f64::INFINITY.category().is_power_of_two());
+    assert!(!f64::NAN.category().is_power_of_two());
+}
+
+#[test]
+fn split_duration_works() {
+    use crate::*;
+
+    assert_eq!(split_duration(1.5), Ok((1, 0.5)));
+    assert_eq!(split_duration(0.25), Ok((0, 0.25)));
+    assert_eq!(split_duration(60.0), Ok((60, 0.0)));
+    assert_eq!(split_duration(-0.0), Ok((0, 0.0)));
+    assert_eq!(split_duration(-1.0), Err(CastError::OutOfRange));
+    assert_eq!(split_duration(-0.5), Err(CastError::OutOfRange));
+    assert_eq!(split_duration(1e30), Err(CastError::OutOfRange));
+    assert_eq!(split_duration(f64::NAN), Err(CastError::WasNaN));
+    assert_eq!(split_duration(f64::INFINITY), Err(CastError::WasInfinite));
+}
+
+#[test]
+fn default_is_positive_zero() {
+    use crate::*;
+
+    assert_eq!(CatFloat::default(), CatFloat::Zero(Sign::Positive));
+    assert_eq!(CatFloat::default(), 0.0f64.category());
+    assert_eq!(CatFloat::EMPTY, CatFloat::default());
+
+    let slots = [CatFloat::default(); 16];
+    assert!(slots.iter().all(CatFloat::is_zero));
+
+    #[cfg(feature = "alloc")]
+    {
+        let v = alloc::vec![CatFloat::default(); 8];
+        assert_eq!(v.len(), 8);
+        assert!(v.iter().all(|c| *c == CatFloat::EMPTY));
+    }
+}
+
+#[test]
+fn signum_parts_works() {
+    use crate::*;
+
+    assert_eq!(
+        (-1.5f64).category().signum_parts(),
+        (Some(-1.0), Some(-1.0))
+    );
+    assert_eq!(1.0f64.category().signum_parts(), (Some(1.0), None));
+    assert_eq!((-0.5f64).category().signum_parts(), (None, Some(-1.0)));
+    assert_eq!(f64::NAN.category().signum_parts(), (None, None));
+    assert_eq!(f64::INFINITY.category().signum_parts(), (None, None));
+
+    let (int, fract) = (-0.0f64).category().signum_parts();
+    assert_eq!(fract, None);
+    assert!(int.unwrap() == 0.0 && int.unwrap().is_sign_negative());
+}
+
+#[test]
+fn classify_bits_works() {
+    use crate::*;
+
+    assert_eq!(
+        classify_bits(1.5f64.to_bits()),
+        CatFloat::IntegerAndFractionalPart(1.0, 0.5)
+    );
+    assert_eq!(
+        classify_bits(f64::INFINITY.to_bits()),
+        CatFloat::Infinity(Sign::Positive)
+    );
+    assert_eq!(
+        classify_bits(0xfff8_0000_0000_0000),
+        CatFloat::Nan {
+            kind: NanKind::Quiet,
+            payload: 0
+        }
+    );
+    assert_eq!(
+        classify_bits(0x7ff0_0000_0000_0001),
+        CatFloat::Nan {
+            kind: NanKind::Signaling,
+            payload: 1
+        }
+    );
+    assert_eq!(classify_bits(1 << 63), CatFloat::Zero(Sign::Negative));
+    assert_eq!(classify_bits(1), CatFloat::Subnormal(5e-324));
+}
+
+#[test]
+fn category_strict_rejects_non_finite() {
+    use crate::*;
+
+    assert_eq!(
+        category_strict(1.5),
+        Ok(CatFloat::IntegerAndFractionalPart(1.0, 0.5))
+    );
+    assert_eq!(category_strict(-0.0), Ok(CatFloat::Zero(Sign::Negative)));
+    assert_eq!(category_strict(f64::NAN), Err(NonFinite::WasNaN));
+    assert_eq!(category_strict(f64::INFINITY), Err(NonFinite::WasInfinite));
+    assert_eq!(
+        category_strict(f64::NEG_INFINITY),
+        Err(NonFinite::WasInfinite)
+    );
+}
+
+#[test]
+fn abs_flips_negative_parts() {
+    use crate::*;
+
+    assert_eq!(
+        (-1.5f64).category().abs(),
+        CatFloat::IntegerAndFractionalPart(1.0, 0.5)
+    );
+    assert_eq!((-0.25f64).category().abs(), CatFloat::FractionLike(0.25));
+    assert_eq!((-3.0f64).category().abs(), CatFloat::IntegerLike(3.0));
+    assert_eq!(
+        f64::NEG_INFINITY.category().abs(),
+        CatFloat::Infinity(Sign::Positive)
+    );
+    assert_eq!((-0.0f64).category().abs(), CatFloat::Zero(Sign::Positive));
+    assert!(f64::NAN.category().abs().is_nan());
+
+    for x in [-1.5f64, 2.25, -1e300, -5e-324] {
+        assert_eq!(x.category().abs(), x.abs().category());
+    }
+}
+
+#[test]
+fn variant_indices_round_trip() {
+    use crate::*;
+
+    let cats = [
+        1.0f64.category(),
+        0.5f64.category(),
+        1.5f64.category(),
+        f64::NAN.category(),
+        f64::INFINITY.category(),
+        0.0f64.category(),
+        5e-324f64.category(),
+        1e300f64.category(),
+    ];
+    for (i, cat) in cats.iter().enumerate() {
+        assert_eq!(usize::from(cat.variant_index()), i);
+
+        let placeholder = CatFloat::from_variant_index(cat.variant_index()).unwrap();
+        assert_eq!(placeholder.variant_index(), cat.variant_index());
+    }
+    assert_eq!(CatFloat::from_variant_index(8), None);
+}
+
+#[test]
+fn reciprocal_category_works() {
+    use crate::*;
+
+    assert_eq!(
+        0.5f64.category().reciprocal_category(),
+        CatFloat::IntegerLike(2.0)
+    );
+    assert_eq!(
+        2.0f64.category().reciprocal_category(),
+        CatFloat::FractionLike(0.5)
+    );
+    assert_eq!(
+        0.0f64.category().reciprocal_category(),
+        CatFloat::Infinity(Sign::Positive)
+    );
+    assert_eq!(
+        (-0.0f64).category().reciprocal_category(),
+        CatFloat::Infinity(Sign::Negative)
+    );
+    assert_eq!(
+        f64::NEG_INFINITY.category().reciprocal_category(),
+        CatFloat::Zero(Sign::Negative)
+    );
+    assert!(f64::NAN.category().reciprocal_category().is_nan());
+}
+
+#[test]
+fn eq_int_is_exact() {
+    use crate::*;
+
+    assert!(3.0f64.category().eq_int(3));
+    assert!((-7.0f64).category().eq_int(-7));
+    assert!(0.0f64.category().eq_int(0));
+    assert!((-0.0f64).category().eq_int(0));
+    assert!(!3.5f64.category().eq_int(3));
+    assert!(!f64::NAN.category().eq_int(0));
+
+    // 9007199254740993 isn't representable; the literal rounds to 2^53.
+    let x = 9007199254740993.0f64.category();
+    assert!(x.eq_int(9007199254740992));
+    assert!(!x.eq_int(9007199254740993));
+    assert!((i64::MIN as f64).category().eq_int(i64::MIN));
+    assert!(!(-(i64::MIN as f64)).category().eq_int(i64::MAX));
+}
+
+#[test]
+fn category_detailed_works() {
+    use crate::*;
+
+    assert_eq!(
+        category_detailed(1.5),
+        CatFloatDetailed {
+            category: CatFloat::IntegerAndFractionalPart(1.0, 0.5),
+            sign: Sign::Positive,
+            exponent: 0,
+            is_subnormal: false,
+            fits_in_i64: false,
+        }
+    );
+    assert_eq!(
+        category_detailed(5e-324),
+        CatFloatDetailed {
+            category: CatFloat::Subnormal(5e-324),
+            sign: Sign::Positive,
+            exponent: -1023,
+            is_subnormal: true,
+            fits_in_i64: false,
+        }
+    );
+    assert_eq!(
+        category_detailed(-2.0),
+        CatFloatDetailed {
+            category: CatFloat::IntegerLike(-2.0),
+            sign: Sign::Negative,
+            exponent: 1,
+            is_subnormal: false,
+            fits_in_i64: true,
+        }
+    );
+    assert_eq!(category_detailed(f64::NAN).exponent, 1024);
+}
+
+#[test]
+fn classify_literal_spots_float_syntax() {
+    use crate::*;
+
+    assert_eq!(
+        classify_literal("3"),
+        Ok((CatFloat::IntegerLike(3.0), false))
+    );
+    assert_eq!(
+        classify_literal("3.0"),
+        Ok((CatFloat::IntegerLike(3.0), true))
+    );
+    assert_eq!(
+        classify_literal("3e2"),
+        Ok((CatFloat::IntegerLike(300.0), true))
+    );
+    assert_eq!(
+        classify_literal("-inf"),
+        Ok((CatFloat::Infinity(Sign::Negative), false))
+    );
+    assert!(classify_literal("3.0.0").is_err());
+}
+
+#[test]
+fn wrapped_fraction_stays_in_unit_interval() {
+    use crate::*;
+
+    assert_eq!((-1.25f64).category().wrapped_fraction(), Some(0.75));
+    assert_eq!(1.25f64.category().wrapped_fraction(), Some(0.25));
+    assert_eq!((-0.5f64).category().wrapped_fraction(), Some(0.5));
+    assert_eq!((-3.0f64).category().wrapped_fraction(), Some(0.0));
+    assert_eq!(f64::NAN.category().wrapped_fraction(), None);
+    assert_eq!(f64::NEG_INFINITY.category().wrapped_fraction(), None);
+
+    for x in [-1e-20f64, -5e-324, -0.0, 1e300, -2.5] {
+        let w = x.category().wrapped_fraction().unwrap();
+        assert!((0.0..1.0).contains(&w), "{x:?} -> {w:?}");
+        assert!(w.is_sign_positive(), "{x:?} -> {w:?}");
+    }
+}
+
+#[test]
+fn classify_into_reuses_output() {
+    use crate::*;
+
+    let mut out = CatFloat::default();
+    for x in [1.5f64, 0.25, -3.0, f64::INFINITY] {
+        x.classify_into(&mut out);
+        assert_eq!(out, x.category());
+    }
+}
+
+#[test]
+fn from_str_works() {
+    use crate::*;
+
+    assert_eq!("0.5".parse::<CatFloat>(), Ok(CatFloat::FractionLike(0.5)));
+    assert_eq!(
+        "inf".parse::<CatFloat>(),
+        Ok(CatFloat::Infinity(Sign::Positive))
+    );
+    assert!("nan".parse::<CatFloat>().unwrap().is_nan());
+    assert!("0.5x".parse::<CatFloat>().is_err());
+}
+
+#[test]
+fn negate_flips_every_finite_variant() {
+    use crate::*;
+
+    assert_eq!(
+        CatFloat::IntegerLike(3.0).negate(),
+        CatFloat::IntegerLike(-3.0)
+    );
+    assert_eq!(
+        CatFloat::FractionLike(0.5).negate(),
+        CatFloat::FractionLike(-0.5)
+    );
+    assert_eq!(
+        CatFloat::IntegerAndFractionalPart(1.0, 0.5).negate(),
+        CatFloat::IntegerAndFractionalPart(-1.0, -0.5)
+    );
+    assert_eq!(
+        CatFloat::Zero(Sign::Positive).negate(),
+        CatFloat::Zero(Sign::Negative)
+    );
+    assert_eq!(
+        CatFloat::Infinity(Sign::Negative).negate(),
+        CatFloat::Infinity(Sign::Positive)
+    );
+    assert!(f64::NAN.category().negate().is_nan());
+
+    for x in [1.5f64, -0.25, 1e300, 5e-324, 0.0] {
+        assert_eq!(x.category().negate(), (-x).category());
+    }
+}
+
+#[test]
+fn category_scaled_works() {
+    use crate::*;
+
+    assert_eq!(category_scaled(0.25, 2), CatFloat::IntegerLike(1.0));
+    assert_eq!(
+        category_scaled(0.375, 2),
+        CatFloat::IntegerAndFractionalPart(1.0, 0.5)
+    );
+    assert_eq!(
+        category_scaled(6.0, -2),
+        CatFloat::IntegerAndFractionalPart(1.0, 0.5)
+    );
+    assert_eq!(category_scaled(1.5, 0), 1.5f64.category());
+    assert_eq!(
+        category_scaled(1.0, 2000),
+        CatFloat::Infinity(Sign::Positive)
+    );
+    assert!(category_scaled(1.0, -1074).is_subnormal());
+    assert!(category_scaled(f64::NAN, 4).is_nan());
+}
+
+#[test]
+fn exact_halves_are_detected() {
+    use crate::*;
+
+    assert!(0.5f64.category().is_exact_half());
+    assert!((-0.5f64).category().is_exact_half());
+    assert!(1.5f64.category().is_exact_half());
+    assert!((-2.5f64).category().is_exact_half());
+    assert!(!2.0f64.category().is_exact_half());
+    assert!(!0.25f64.category().is_exact_half());
+    assert!(!1.5000001f64.category().is_exact_half());
+    assert!(!f64::NAN.category().is_exact_half());
+}
+
+#[test]
+fn checked_as_respects_ranges() {
+    use crate::*;
+
+    assert_eq!(200.0f64.category().checked_as::<u8>(), Some(200u8));
+    assert_eq!(300.0f64.category().checked_as::<u8>(), None);
+    assert_eq!(255.0f64.category().checked_as::<u8>(), Some(255u8));
+    assert_eq!(256.0f64.category().checked_as::<u8>(), None);
+    assert_eq!((-1.0f64).category().checked_as::<u32>(), None);
+    assert_eq!((-128.0f64).category().checked_as::<i8>(), Some(-128i8));
+    assert_eq!((-129.0f64).category().checked_as::<i8>(), None);
+    assert_eq!((-0.0f64).category().checked_as::<u8>(), Some(0u8));
+    assert_eq!(1.5f64.category().checked_as::<i32>(), None);
+    assert_eq!(f64::NAN.category().checked_as::<i32>(), None);
+
+    assert_eq!((u64::MAX as f64).category().checked_as::<u64>(), None);
+    assert_eq!(
+        (i64::MIN as f64).category().checked_as::<i64>(),
+        Some(i64::MIN)
+    );
+    assert_eq!(
+        1e38f64.category().checked_as::<u128>(),
+        Some(1e38f64 as u128)
+    );
+    assert_eq!(HUGE_INTEGER_LIMIT.category().checked_as::<u128>(), None);
+}
+
+#[test]
+fn partial_cmp_orders_by_value() {
+    use crate::*;
+
+    assert!(CatFloat::IntegerLike(1.0) < CatFloat::IntegerAndFractionalPart(1.0, 0.5));
+    assert!(CatFloat::FractionLike(0.5) < CatFloat::IntegerLike(1.0));
+    assert!((-1.5f64).category() < 0.25f64.category());
+    assert!(f64::NEG_INFINITY.category() < f64::MIN.category());
+    assert!(f64::INFINITY.category() > 1e300f64.category());
+    assert!((-0.0f64).category() < 0.0f64.category());
+    assert_eq!(
+        1.5f64.category().partial_cmp(&1.5f64.category()),
+        Some(Ordering::Equal)
+    );
+
+    let nan = f64::NAN.category();
+    assert_eq!(nan.partial_cmp(&1.0f64.category()), None);
+    assert_eq!(1.0f64.category().partial_cmp(&nan), None);
+    assert_eq!(nan.partial_cmp(&nan), None);
+    assert_eq!(
+        CatFloat::IntegerLike(0.0).partial_cmp(&CatFloat::Zero(Sign::Positive)),
+        None
+    );
+}
+
+#[test]
+fn raw_split_matches_trunc_and_fract() {
+    use crate::*;
+
+    assert_eq!(3.0f64.category().raw_split(), Some((3.0, 0.0)));
+    assert_eq!(0.5f64.category().raw_split(), Some((0.0, 0.5)));
+    assert_eq!(1.5f64.category().raw_split(), Some((1.0, 0.5)));
+    assert_eq!(0.0f64.category().raw_split(), Some((0.0, 0.0)));
+    assert_eq!(5e-324f64.category().raw_split(), Some((0.0, 5e-324)));
+    assert_eq!(1e300f64.category().raw_split(), Some((1e300, 0.0)));
+    assert_eq!(f64::NAN.category().raw_split(), None);
+    assert_eq!(f64::INFINITY.category().raw_split(), None);
+
+    for x in [-2.75f64, -0.25, -0.0, 7.0] {
+        let (int, fract) = x.category().raw_split().unwrap();
+        assert_eq!(int, FloatCore::trunc(x), "{x:?}");
+        assert_eq!(fract, FloatCore::fract(x), "{x:?}");
+    }
+}
+
+#[test]
+fn category_diff_reports_changes() {
+    use crate::*;
+
+    let diff = category_diff(
+        CatFloat::IntegerAndFractionalPart(1.0, 0.5),
+        CatFloat::IntegerAndFractionalPart(1.0, 0.6),
+    );
+    assert!(!diff.variant_changed);
+    assert_eq!(diff.integer_delta, Some(0.0));
+    assert!((diff.fraction_delta.unwrap() - 0.1).abs() < 1e-12);
+
+    let diff = category_diff(CatFloat::IntegerLike(2.0), CatFloat::FractionLike(0.5));
+    assert!(diff.variant_changed);
+    assert_eq!(diff.integer_delta, Some(-2.0));
+    assert_eq!(diff.fraction_delta, Some(0.5));
+
+    let diff = category_diff(1.0f64.category(), f64::NAN.category());
+    assert!(diff.variant_changed);
+    assert_eq!(diff.integer_delta, None);
+    assert_eq!(diff.fraction_delta, None);
+}
+
+#[test]
+fn into_parts_destructures() {
+    use crate::*;
+
+    let cat = 1.5f64.category();
+    let parts = cat.into_parts();
+    assert_eq!(parts, (Some(1.0), Some(0.5), false, false));
+
+    assert_eq!(
+        3.0f64.category().into_parts(),
+        (Some(3.0), None, false, false)
+    );
+    assert_eq!(
+        (-0.25f64).category().into_parts(),
+        (None, Some(-0.25), false, false)
+    );
+    assert_eq!(f64::NAN.category().into_parts(), (None, None, true, false));
+    assert_eq!(
+        f64::INFINITY.category().into_parts(),
+        (None, None, false, true)
+    );
+}
+
+#[test]
+fn midpoint_category_works() {
+    use crate::*;
+
+    let one = CatFloat::IntegerLike(1.0);
+    assert_eq!(
+        one.midpoint_category(&CatFloat::IntegerLike(2.0)),
+        CatFloat::IntegerAndFractionalPart(1.0, 0.5)
+    );
+    assert_eq!(
+        one.midpoint_category(&CatFloat::Zero(Sign::Positive)),
+        CatFloat::FractionLike(0.5)
+    );
+    assert_eq!(
+        f64::MAX.category().midpoint_category(&f64::MAX.category()),
+        f64::MAX.category()
+    );
+    assert_eq!(
+        f64::MAX.category().midpoint_category(&f64::MIN.category()),
+        CatFloat::Zero(Sign::Positive)
+    );
+    assert_eq!(
+        f64::MAX.category().midpoint_category(&5e-324f64.category()),
+        (f64::MAX / 2.0).category()
+    );
+    assert_eq!(
+        5e-324f64
+            .category()
+            .midpoint_category(&(-5e-324f64).category()),
+        CatFloat::Zero(Sign::Positive)
+    );
+    assert_eq!(
+        f64::MIN_POSITIVE
+            .category()
+            .midpoint_category(&f64::MIN_POSITIVE.category()),
+        f64::MIN_POSITIVE.category()
+    );
+    assert_eq!(
+        one.midpoint_category(&f64::INFINITY.category()),
+        CatFloat::Infinity(Sign::Positive)
+    );
+    assert!(one.midpoint_category(&f64::NAN.category()).is_nan());
+    assert!(f64::INFINITY
+        .category()
+        .midpoint_category(&f64::NEG_INFINITY.category())
+        .is_nan());
+}
+
+#[test]
+fn small_integer_table_matches_category() {
+    use crate::*;
+
+    assert_eq!(classify_small_u8(42), CatFloat::IntegerLike(42.0));
+    assert_eq!(classify_small_u8(42), 42.0f64.category());
+    assert_eq!(classify_small_u8(0), CatFloat::Zero(Sign::Positive));
+
+    for n in 0..=u8::MAX {
+        assert_eq!(classify_small_u8(n), f64::from(n).category());
+    }
+}
+
+#[test]
+fn nan_payloads_are_kept() {
+    use crate::*;
+
+    let quiet = f64::from_bits(f64::NAN.to_bits() | 0x1234_5678);
+    let cat = quiet.category();
+    assert!(cat.is_nan());
+    assert_eq!(cat.nan_payload(), Some(0x1234_5678));
+    assert_eq!(cat.to_f64().to_bits(), quiet.to_bits());
+    assert_eq!(classify_f64(quiet), cat);
+    assert_eq!(category_of(quiet), cat);
+
+    let signaling = f64::from_bits(0x7ff0_0000_0000_00ff);
+    let cat = signaling.category();
+    assert!(cat.is_signaling_nan());
+    assert_eq!(cat.nan_payload(), Some(0xff));
+    assert_eq!(cat.to_f64().to_bits(), signaling.to_bits());
+
+    assert_eq!(f64::NAN.category().nan_payload(), Some(0));
+    assert_eq!(1.5f64.category().nan_payload(), None);
+    assert_ne!(
+        cat,
+        CatFloat::Nan {
+            kind: NanKind::Signaling,
+            payload: 0
+        }
+    );
+    assert!(CatFloat::Nan {
+        kind: NanKind::Signaling,
+        payload: 0
+    }
+    .to_f64()
+    .is_nan());
+}
+
+#[test]
+fn scale_reclassifies() {
+    use crate::*;
+
+    assert_eq!(
+        CatFloat::FractionLike(0.5).scale(4.0),
+        CatFloat::IntegerLike(2.0)
+    );
+    assert_eq!(
+        CatFloat::IntegerLike(3.0).scale(0.5),
+        CatFloat::IntegerAndFractionalPart(1.0, 0.5)
+    );
+    assert_eq!(
+        CatFloat::IntegerLike(1.0).scale(f64::INFINITY),
+        CatFloat::Infinity(Sign::Positive)
+    );
+    assert_eq!(
+        f64::MAX.category().scale(2.0),
+        CatFloat::Infinity(Sign::Positive)
+    );
+    assert!(CatFloat::Zero(Sign::Positive).scale(f64::INFINITY).is_nan());
+    assert!(f64::NAN.category().scale(2.0).is_nan());
+    assert_eq!(
+        CatFloat::IntegerLike(2.0).scale(-0.0),
+        CatFloat::Zero(Sign::Negative)
+    );
+}
+
+#[test]
+#[cfg(feature = "arbitrary")]
+fn arbitrary_builds_valid_categories() {
+    use crate::*;
+    use arbitrary::{Arbitrary, Unstructured};
+
+    let bytes: std::vec::Vec<u8> = (0..=255u8).cycle().take(4096).collect();
+    let mut u = Unstructured::new(&bytes);
+    for _ in 0..64 {
+        let cat = CatFloat::arbitrary(&mut u).unwrap();
+        if !cat.is_nan() {
+            assert_eq!(cat.to_f64().category(), cat);
+        }
+    }
+
+    let mut empty = Unstructured::new(&[]);
+    assert!(CatFloat::arbitrary(&mut empty).is_ok());
+}
+
+#[test]
+fn variant_names_work() {
+    use crate::*;
+
+    assert_eq!(1.0f64.category().variant_name(), "IntegerLike");
+    assert_eq!(0.5f64.category().variant_name(), "FractionLike");
+    assert_eq!(1.5f64.category().variant_name(), "IntegerAndFractionalPart");
+    assert_eq!(f64::NAN.category().variant_name(), "Nan");
+    assert_eq!(f64::INFINITY.category().variant_name(), "Infinity");
+    assert_eq!(0.0f64.category().variant_name(), "Zero");
+    assert_eq!(5e-324f64.category().variant_name(), "Subnormal");
+    assert_eq!(1e300f64.category().variant_name(), "HugeInteger");
+}
+
+#[test]
+fn visitor_dispatches_per_variant() {
+    use crate::*;
+    use std::format;
+    use std::string::String;
+
+    struct Describe;
+
+    impl CatFloatVisitor for Describe {
+        type Output = String;
+        fn on_integer_like(&mut self, n: f64) -> String {
+            format!("int {n}")
+        }
+        fn on_fraction_like(&mut self, n: f64) -> String {
+            format!("fract {n}")
+        }
+        fn on_mixed(&mut self, int: f64, fract: f64) -> String {
+            format!("mixed {int} {fract}")
+        }
+        fn on_nan(&mut self, kind: NanKind, _: u64) -> String {
+            format!("nan {kind:?}")
+        }
+        fn on_infinity(&mut self, sign: Sign) -> String {
+            format!("inf {sign:?}")
+        }
+        fn on_huge_integer(&mut self, _: f64) -> String {
+            String::from("huge")
+        }
+    }
+
+    let describe = |x: f64| x.category().accept(&mut Describe);
+    assert_eq!(describe(3.0), "int 3");
+    assert_eq!(describe(0.5), "fract 0.5");
+    assert_eq!(describe(1.5), "mixed 1 0.5");
+    assert_eq!(describe(f64::NAN), "nan Quiet");
+    assert_eq!(describe(f64::NEG_INFINITY), "inf Negative");
+    assert_eq!(describe(-0.0), "int -0");
+    assert!(describe(5e-324).starts_with("fract 0.0"));
+    assert_eq!(describe(1e300), "huge");
+}
+
+#[test]
+fn saturating_as_i64_clamps() {
+    use crate::*;
+
+    assert_eq!(1e300f64.category().saturating_as_i64(), i64::MAX);
+    assert_eq!((-1e300f64).category().saturating_as_i64(), i64::MIN);
+    assert_eq!(f64::NAN.category().saturating_as_i64(), 0);
+    assert_eq!(3.7f64.category().saturating_as_i64(), 3);
+    assert_eq!((-3.7f64).category().saturating_as_i64(), -3);
+    assert_eq!(0.9f64.category().saturating_as_i64(), 0);
+    assert_eq!(42.0f64.category().saturating_as_i64(), 42);
+    assert_eq!(f64::INFINITY.category().saturating_as_i64(), i64::MAX);
+    assert_eq!(f64::NEG_INFINITY.category().saturating_as_i64(), i64::MIN);
+}
+
+#[test]
+fn combine_applies_arithmetic() {
+    use crate::*;
+
+    let one = CatFloat::IntegerLike(1.0);
+    let half = CatFloat::FractionLike(0.5);
+    let zero = CatFloat::Zero(Sign::Positive);
+
+    assert_eq!(
+        one.combine(&half, ArithOp::Add),
+        CatFloat::IntegerAndFractionalPart(1.0, 0.5)
+    );
+    assert_eq!(one.combine(&half, ArithOp::Sub), half);
+    assert_eq!(
+        half.combine(&half, ArithOp::Mul),
+        CatFloat::FractionLike(0.25)
+    );
+    assert_eq!(one.combine(&half, ArithOp::Div), CatFloat::IntegerLike(2.0));
+    assert_eq!(
+        one.combine(&zero, ArithOp::Div),
+        CatFloat::Infinity(Sign::Positive)
+    );
+    assert_eq!(
+        one.combine(&CatFloat::Zero(Sign::Negative), ArithOp::Div),
+        CatFloat::Infinity(Sign::Negative)
+    );
+    assert!(zero.combine(&zero, ArithOp::Div).is_nan());
+    assert!(one.combine(&f64::NAN.category(), ArithOp::Add).is_nan());
+    assert!(f64::INFINITY
+        .category()
+        .combine(&f64::INFINITY.category(), ArithOp::Sub)
+        .is_nan());
+}
+
+#[test]
+fn negative_fractions_are_distinguished() {
+    use crate::*;
+
+    assert_eq!((-0.2f64).category(), CatFloat::FractionLike(-0.2));
+    assert!((-0.2f64).category().is_negative_fraction());
+    assert!(!0.2f64.category().is_negative_fraction());
+    assert!(!(-1.2f64).category().is_negative_fraction());
+
+    assert_eq!((-0.2f64).category().normalized_fraction_like(), Some(0.2));
+    assert_eq!(0.2f64.category().normalized_fraction_like(), Some(0.2));
+    assert_eq!(1.2f64.category().normalized_fraction_like(), None);
+    assert_eq!(f64::NAN.category().normalized_fraction_like(), None);
+}
+
+#[test]
+#[cfg(feature = "json")]
+fn json_values_stay_finite() {
+    use crate::*;
+
+    for x in [
+        1.0f64,
+        0.5,
+        1.5,
+        f64::NAN,
+        f64::NEG_INFINITY,
+        -0.0,
+        5e-324,
+        1e300,
+    ] {
+        let json = x.category().to_json_value().to_string();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, x.category().to_json_value(), "{json}");
+    }
+
+    let nan = f64::NAN.category().to_json_value();
+    assert_eq!(nan["kind"], "nan");
+    assert!(nan["int"].is_null() && nan["fract"].is_null());
+
+    let inf = f64::NEG_INFINITY.category().to_json_value();
+    assert_eq!(inf["kind"], "infinity");
+    assert_eq!(inf["sign"], "negative");
+
+    let mixed = (-2.25f64).category().to_json_value();
+    assert_eq!(mixed["kind"], "mixed");
+    assert_eq!(mixed["int"], -2.0);
+    assert_eq!(mixed["fract"], -0.25);
+}
+
+#[test]
+fn try_reduce_to_integer_snaps() {
+    use crate::*;
+
+    let near = CatFloat::IntegerAndFractionalPart(2.0, 0.0000001);
+    assert_eq!(near.try_reduce_to_integer(1e-6), CatFloat::IntegerLike(2.0));
+    assert_eq!(near.try_reduce_to_integer(1e-9), near);
+
+    let half = CatFloat::IntegerAndFractionalPart(2.0, 0.5);
+    assert_eq!(half.try_reduce_to_integer(1e-6), half);
+
+    assert_eq!(
+        2.9999999f64.category().try_reduce_to_integer(1e-6),
+        CatFloat::IntegerLike(3.0)
+    );
+    assert_eq!(
+        0.0000001f64.category().try_reduce_to_integer(1e-6),
+        CatFloat::Zero(Sign::Positive)
+    );
+    assert!(f64::NAN.category().try_reduce_to_integer(1.0).is_nan());
+    assert_eq!(
+        f64::INFINITY.category().try_reduce_to_integer(1.0),
+        CatFloat::Infinity(Sign::Positive)
+    );
+}
+
+#[test]
+fn lerp_category_works() {
+    use crate::*;
+
+    let zero = CatFloat::IntegerLike(0.0);
+    let two = CatFloat::IntegerLike(2.0);
+    assert_eq!(zero.lerp_category(&two, 0.25), CatFloat::FractionLike(0.5));
+    assert_eq!(zero.lerp_category(&two, 0.75), 1.5f64.category());
+    assert_eq!(zero.lerp_category(&two, 1.0), two);
+    assert_eq!(zero.lerp_category(&two, 2.0), CatFloat::IntegerLike(4.0));
+    assert_eq!(zero.lerp_category(&two, -0.5), CatFloat::IntegerLike(-1.0));
+
+    let inf = f64::INFINITY.category();
+    assert_eq!(zero.lerp_category(&inf, 0.5), inf);
+    assert!(inf.lerp_category(&inf, 0.5).is_nan());
+    assert!(zero.lerp_category(&f64::NAN.category(), 0.5).is_nan());
+}
+
+#[test]
+fn parts_iter_yields_present_parts() {
+    use crate::*;
+    use std::vec::Vec;
+
+    let parts = |x: f64| x.category().parts_iter().collect::<Vec<f64>>();
+    assert_eq!(parts(3.0), [3.0]);
+    assert_eq!(parts(0.5), [0.5]);
+    assert_eq!(parts(-2.25), [-2.0, -0.25]);
+    assert_eq!(parts(5e-324), [5e-324]);
+    assert_eq!(parts(1e40), [1e40]);
+    assert!(parts(-0.0)[0].is_sign_negative());
+    assert!(parts(f64::NAN).is_empty());
+    assert!(parts(f64::NEG_INFINITY).is_empty());
+
+    assert_eq!((-2.25f64).category().parts_iter().sum::<f64>(), -2.25);
+}
+
+#[test]
+fn category_branchless_matches_category_fast() {
+    use crate::*;
+
+    let same = |a: CatFloat, b: CatFloat| match (a, b) {
+        (
+            CatFloat::IntegerAndFractionalPart(ai, af),
+            CatFloat::IntegerAndFractionalPart(bi, bf),
+        ) => ai.to_bits() == bi.to_bits() && af.to_bits() == bf.to_bits(),
+        _ => a == b && a.to_f64().to_bits() == b.to_f64().to_bits(),
+    };
+
+    let specials = [
+        0.0,
+        -0.0,
+        1.0,
+        -1.5,
+        0.5,
+        4503599627370495.5,
+        4503599627370496.0,
+        HUGE_INTEGER_LIMIT,
+        -HUGE_INTEGER_LIMIT,
+        f64::MIN_POSITIVE,
+        -5e-324,
+        f64::MAX,
+        f64::NEG_INFINITY,
+        f64::NAN,
+        f64::from_bits(SIGNALING_NAN_BITS),
+    ];
+    for x in specials {
+        assert!(same(category_branchless(x), category_fast(x)), "{x:?}");
+    }
+
+    let mut bits = 0u64;
+    for _ in 0..1_000_000 {
+        bits = bits.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let x = f64::from_bits(bits);
+        assert!(same(category_branchless(x), category_fast(x)), "{bits:#x}");
+    }
+}
+
+#[test]
+fn classify_modulo_is_euclidean() {
+    use crate::*;
+
+    assert_eq!(
+        7.5f64.category().classify_modulo(2.0),
+        CatFloat::IntegerAndFractionalPart(1.0, 0.5)
+    );
+    assert_eq!(
+        (-1.0f64).category().classify_modulo(3.0),
+        CatFloat::IntegerLike(2.0)
+    );
+    assert_eq!(
+        (-1.0f64).category().classify_modulo(-3.0),
+        CatFloat::IntegerLike(2.0)
+    );
+    assert_eq!(
+        (-0.25f64).category().classify_modulo(1.0),
+        CatFloat::FractionLike(0.75)
+    );
+    assert_eq!(
+        (-4.0f64).category().classify_modulo(2.0),
+        CatFloat::Zero(Sign::Positive)
+    );
+    assert_eq!(
+        (-0.0f64).category().classify_modulo(2.0),
+        CatFloat::Zero(Sign::Positive)
+    );
+
+    assert_eq!(
+        (-1e-20f64).category().classify_modulo(1.0),
+        CatFloat::Zero(Sign::Positive)
+    );
+    assert_eq!(
+        (-5e-324f64).category().classify_modulo(-3.0),
+        CatFloat::Zero(Sign::Positive)
+    );
+
+    for divisor in [0.0, -0.0, f64::INFINITY, f64::NAN] {
+        assert!(1.5f64.category().classify_modulo(divisor).is_nan());
+    }
+    assert!(f64::NAN.category().classify_modulo(2.0).is_nan());
+    assert!(f64::INFINITY.category().classify_modulo(2.0).is_nan());
+}
+
+#[test]
+fn fractional_precision_bits_counts_binary_digits() {
+    use crate::*;
+
+    let bits = |x: f64| x.category().fractional_precision_bits();
+    assert_eq!(bits(0.5), Some(1));
+    assert_eq!(bits(0.25), Some(2));
+    assert_eq!(bits(0.75), Some(2));
+    assert_eq!(bits(-2.125), Some(3));
+    assert_eq!(bits(0.1), Some(55));
+    assert_eq!(bits(5e-324), Some(1074));
+    assert_eq!(bits(4503599627370495.5), Some(1));
+    assert_eq!(bits(3.0), Some(0));
+    assert_eq!(bits(-0.0), Some(0));
+    assert_eq!(bits(1e40), Some(0));
+    assert_eq!(bits(f64::NAN), None);
+    assert_eq!(bits(f64::NEG_INFINITY), None);
+}
+
+#[test]
+fn references_and_boxes_classify() {
+    use crate::*;
+
+    fn classify<T: Category>(x: T) -> T::D {
+        x.category()
+    }
+
+    assert_eq!(classify(&1.5), CatFloat::IntegerAndFractionalPart(1.0, 0.5));
+    #[cfg(feature = "alloc")]
+    assert_eq!(
+        classify(alloc::boxed::Box::new(0.5)),
+        CatFloat::FractionLike(0.5)
+    );
+}
+
+#[test]
+fn round_trip_error_is_zero_for_finite() {
+    use crate::*;
+
+    for x in [
+        0.0,
+        -0.0,
+        1.0,
+        0.1,
+        -2.75,
+        4503599627370495.5,
+        5e-324,
+        f64::MAX,
+        f64::MIN,
+        1e40,
+    ] {
+        assert_eq!(x.category().round_trip_error(x), 0.0, "{x:?}");
+    }
+
+    assert_eq!(CatFloat::IntegerLike(1.0).round_trip_error(1.25), 0.25);
+    assert!(f64::INFINITY
+        .category()
+        .round_trip_error(f64::INFINITY)
+        .is_nan());
+    assert!(f64::NAN.category().round_trip_error(f64::NAN).is_nan());
+}
+
+#[test]
+#[cfg(feature = "complex")]
+fn complex_parts_classify_separately() {
+    use crate::*;
+
+    assert_eq!(
+        num::Complex::new(1.5, -0.5).category(),
+        (
+            CatFloat::IntegerAndFractionalPart(1.0, 0.5),
+            CatFloat::FractionLike(-0.5)
+        )
+    );
+    let (re, im) = num::Complex::new(f64::NAN, -0.0).category();
+    assert!(re.is_nan());
+    assert_eq!(im, CatFloat::Zero(Sign::Negative));
+}
+
+#[test]
+fn clamp_to_range_reclassifies() {
+    use crate::*;
+
+    assert_eq!(
+        CatFloat::FractionLike(0.5).clamp_to_range(1.0, 2.0),
+        CatFloat::IntegerLike(1.0)
+    );
+    assert_eq!(
+        CatFloat::IntegerLike(5.0).clamp_to_range(1.0, 2.5),
+        CatFloat::IntegerAndFractionalPart(2.0, 0.5)
+    );
+    assert_eq!(
+        1.5f64.category().clamp_to_range(1.0, 2.0),
+        CatFloat::IntegerAndFractionalPart(1.0, 0.5)
+    );
+    assert_eq!(
+        (-3.0f64).category().clamp_to_range(0.0, f64::INFINITY),
+        CatFloat::Zero(Sign::Positive)
+    );
+    assert_eq!(
+        f64::NEG_INFINITY.category().clamp_to_range(-1.0, 1.0),
+        CatFloat::IntegerLike(-1.0)
+    );
+    assert!(f64::NAN.category().clamp_to_range(1.0, 2.0).is_nan());
+}
+
+#[test]
+#[should_panic(expected = "min > max")]
+fn clamp_to_range_rejects_inverted_bounds() {
+    use crate::*;
+
+    let _ = CatFloat::FractionLike(0.5).clamp_to_range(2.0, 1.0);
+}
+
+#[test]
+fn with_fraction_replaced_keeps_the_integer_part() {
+    use crate::*;
+
+    assert_eq!(
+        CatFloat::IntegerAndFractionalPart(3.0, 0.5).with_fraction_replaced(0.25),
+        CatFloat::IntegerAndFractionalPart(3.0, 0.25)
+    );
+    assert_eq!(
+        CatFloat::IntegerAndFractionalPart(3.0, 0.5).with_fraction_replaced(0.0),
+        CatFloat::IntegerLike(3.0)
+    );
+    assert_eq!(
+        CatFloat::IntegerLike(2.0).with_fraction_replaced(0.5),
+        CatFloat::IntegerAndFractionalPart(2.0, 0.5)
+    );
+    assert_eq!(
+        (-3.5f64).category().with_fraction_replaced(0.25),
+        CatFloat::IntegerAndFractionalPart(-3.0, -0.25)
+    );
+    assert_eq!(
+        CatFloat::FractionLike(-0.5).with_fraction_replaced(0.75),
+        CatFloat::FractionLike(-0.75)
+    );
+    assert_eq!(
+        CatFloat::Zero(Sign::Negative).with_fraction_replaced(0.0),
+        CatFloat::Zero(Sign::Negative)
+    );
+
+    let inf = f64::INFINITY.category();
+    assert_eq!(inf.with_fraction_replaced(0.5), inf);
+    assert!(f64::NAN.category().with_fraction_replaced(0.5).is_nan());
+}
+
+#[test]
+#[should_panic(expected = "outside 0.0..1.0")]
+fn with_fraction_replaced_rejects_whole_fractions() {
+    use crate::*;
+
+    let _ = CatFloat::IntegerLike(1.0).with_fraction_replaced(1.0);
+}
+
+#[test]
+fn edge_cases_classify_consistently() {
+    use crate::*;
+
+    let two_52 = 4503599627370496.0;
+    let two_53 = 9007199254740992.0;
+    let cases = [
+        (0.0, CatFloat::Zero(Sign::Positive)),
+        (-0.0, CatFloat::Zero(Sign::Negative)),
+        (5e-324, CatFloat::Subnormal(5e-324)),
+        (-5e-324, CatFloat::Subnormal(-5e-324)),
+        (f64::MIN_POSITIVE, CatFloat::FractionLike(f64::MIN_POSITIVE)),
+        (-1e-300, CatFloat::FractionLike(-1e-300)),
+        (two_52, CatFloat::IntegerLike(two_52)),
+        (-two_52, CatFloat::IntegerLike(-two_52)),
+        (
+            two_52 - 0.5,
+            CatFloat::IntegerAndFractionalPart(two_52 - 1.0, 0.5),
+        ),
+        (
+            -(two_52 - 0.5),
+            CatFloat::IntegerAndFractionalPart(1.0 - two_52, -0.5),
+        ),
+        (two_53, CatFloat::IntegerLike(two_53)),
+        (two_53 + 2.0, CatFloat::IntegerLike(two_53 + 2.0)),
+        (f64::MAX, CatFloat::HugeInteger(f64::MAX)),
+        (f64::MIN, CatFloat::HugeInteger(f64::MIN)),
+    ];
+
+    for (x, expected) in cases {
+        let paths = [
+            ("category", x.category()),
+            ("category_fast", category_fast(x)),
+            ("category_branchless", category_branchless(x)),
+            ("category_of", category_of(x)),
+            ("classify_f64", classify_f64(x)),
+            ("classify_bits", classify_bits(x.to_bits())),
+            ("Classifier", Classifier::new().classify(x)),
+        ];
+        for (name, cat) in paths {
+            assert_eq!(cat, expected, "{name}({x:e})");
+            // `==` can't tell zeros apart, so compare the rebuilt bits too.
+            assert_eq!(cat.to_f64().to_bits(), x.to_bits(), "{name}({x:e})");
+        }
+    }
+
+    // Every zero keeps its sign through the helpers that rebuild or split it.
+    let negative_zero = (-0.0f64).category();
+    assert_ne!(negative_zero, 0.0f64.category());
+    assert_eq!(negative_zero.sign(), Some(Sign::Negative));
+    assert!(negative_zero.integer_part().unwrap().is_sign_negative());
+    assert_eq!(negative_zero.negate(), CatFloat::Zero(Sign::Positive));
+    assert_eq!(negative_zero.abs(), CatFloat::Zero(Sign::Positive));
+    assert_eq!(
+        negative_zero.partial_cmp(&0.0f64.category()),
+        Some(Ordering::Less)
+    );
+    assert_eq!(classify_str("-0"), Ok(negative_zero));
+    assert_eq!(category_with_epsilon(-1e-300, 1e-9), negative_zero);
+    assert_eq!(
+        (-0.4f64).category().round_to_category().to_bits(),
+        (-0.0f64).to_bits()
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn continued_fraction_expands() {
+    use crate::*;
+    use std::vec::Vec;
+
+    let cf = |x: f64, n: usize| x.category().continued_fraction(n).unwrap();
+    assert_eq!(cf(0.5, 10), [0, 2]);
+    assert_eq!(cf(1.5, 10), [1, 2]);
+    assert_eq!(cf(1.25, 10), [1, 4]);
+    assert_eq!(cf(-1.5, 10), [-2, 2]);
+    assert_eq!(cf(2.75, 10), [2, 1, 3]);
+    assert_eq!(cf(-0.25, 10), [-1, 1, 3]);
+    assert_eq!(cf(3.0, 10), [3]);
+    assert_eq!(cf(-7.0, 10), [-7]);
+    assert_eq!(cf(-0.0, 10), [0]);
+    assert_eq!(cf(core::f64::consts::PI, 4), [3, 7, 15, 1]);
+    assert_eq!(cf(2.75, 2), [2, 1]);
+    assert!(cf(2.75, 0).is_empty());
+
+    // 0.3 is really 5404319552844595 / 2^54, whose expansion has a long exact tail.
+    assert_eq!(cf(0.3, 10), [0, 3, 2, 1, 900719925474098, 2]);
+    assert_eq!(cf(-0.3, 10), [-1, 1, 2, 2, 1, 900719925474098, 2]);
+    assert_eq!(cf(1.0 / 3.0, 10), [0, 3, 6004799503160661]);
+
+    // The next term here would be far beyond an i64.
+    assert_eq!(cf(1e-300, 10), [0]);
+    assert_eq!(cf(-1e-300, 10), [-1, 1]);
+    assert_eq!(cf(-1e-300, 1), [-1]);
+    assert_eq!(cf(5e-324, 10), [0]);
+    assert_eq!(cf(-9.223372036854776e18, 10), [i64::MIN]);
+
+    // A floor outside i64 isn't an expansion at all.
+    let none = |x: f64| x.category().continued_fraction(10);
+    assert_eq!(none(1e300), None::<Vec<i64>>);
+    assert_eq!(none(9.223372036854776e18), None);
+    assert_eq!(none(f64::NAN), None);
+    assert_eq!(none(f64::NEG_INFINITY), None);
+}
+
+#[test]
+fn equality_is_reflexive_for_hand_built_nans() {
+    use crate::*;
+    use std::collections::HashSet;
+
+    let odd = [
+        CatFloat::FractionLike(f64::NAN),
+        CatFloat::IntegerLike(-f64::NAN),
+        CatFloat::IntegerAndFractionalPart(1.0, f64::NAN),
+    ];
+    for c in odd {
+        assert_eq!(c, c, "{c:?}");
+    }
+    assert_eq!(
+        CatFloat::FractionLike(f64::NAN),
+        CatFloat::FractionLike(f64::from_bits(f64::NAN.to_bits() | 1))
+    );
+    assert_eq!(CatFloat::IntegerLike(-0.0), CatFloat::IntegerLike(0.0));
+    assert_ne!(
+        CatFloat::FractionLike(f64::NAN),
+        CatFloat::IntegerLike(f64::NAN)
+    );
+
+    let set: HashSet<CatFloat> = odd.iter().chain(&odd).copied().collect();
+    assert_eq!(set.len(), odd.len());
+    let zeros: HashSet<CatFloat> = [CatFloat::IntegerLike(-0.0), CatFloat::IntegerLike(0.0)]
+        .into_iter()
+        .collect();
+    assert_eq!(zeros.len(), 1);
 }
@@ -0,0 +1,50 @@
+//! An `f64` that's known not to be NaN.
+
+use crate::{CatFloat, Category};
+
+/// An `f64` that's never NaN, so its classification is never [`CatFloat::Nan`].
+///
+/// # Examples:
+/// ```rust
+/// # use floating_cat::*;
+/// let x = NonNan::new(1.5).unwrap();
+/// assert_eq!(x.category(), CatFloat::IntegerAndFractionalPart(1.0, 0.5));
+/// assert_eq!(NonNan::new(f64::NAN), None);
+/// ```
+#[derive(Debug, PartialEq, PartialOrd, Copy, Clone)]
+pub struct NonNan(f64);
+
+impl NonNan {
+    /// Wraps `x`, or returns `None` if it's NaN.
+    pub fn new(x: f64) -> Option<Self> {
+        if x.is_nan() {
+            return None;
+        }
+        Some(Self(x))
+    }
+
+    /// The wrapped float.
+    pub fn get(self) -> f64 {
+        self.0
+    }
+}
+
+impl Category for NonNan {
+    type D = CatFloat;
+    fn category(&self) -> Self::D {
+        self.0.category()
+    }
+}
+
+#[test]
+fn non_nan_rejects_nan() {
+    assert_eq!(NonNan::new(f64::NAN), None);
+    assert_eq!(NonNan::new(-f64::NAN), None);
+
+    let x = NonNan::new(-0.25).unwrap();
+    assert_eq!(x.get(), -0.25);
+    assert_eq!(x.category(), CatFloat::FractionLike(-0.25));
+
+    let inf = NonNan::new(f64::INFINITY).unwrap();
+    assert!(inf.category().is_positive_infinity());
+}
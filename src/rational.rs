@@ -0,0 +1,223 @@
+//! Classifying exact rationals, behind the `rational` feature.
+
+use alloc::format;
+use alloc::string::String;
+
+use num::traits::Zero;
+use num::{BigInt, BigRational};
+
+use crate::{exact_continued_fraction, CatFloat, Category};
+
+/// The category of a [`BigRational`].
+///
+/// Rationals can't be NaN or infinite, so only the finite splits exist,
+/// and zero is simply [`IntegerLike`].
+///
+/// [`IntegerLike`]: CatRational::IntegerLike
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum CatRational {
+    /// For rationals like `4/1`, with no fractional part.
+    IntegerLike(BigInt),
+
+    /// For rationals like `1/2` or `-2/3`, where there's no integer part.
+    FractionLike(BigRational),
+
+    /// The Integer and Fractional parts of a rational, in that order.
+    /// Both share the rational's sign, as with [`CatFloat`](crate::CatFloat).
+    IntegerAndFractionalPart(BigInt, BigRational),
+}
+
+impl CatRational {
+    /// Returns `true` if the Classified rational is [`IntegerLike`].
+    ///
+    /// [`IntegerLike`]: CatRational::IntegerLike
+    #[must_use]
+    pub fn is_integer_like(&self) -> bool {
+        matches!(self, Self::IntegerLike(..))
+    }
+
+    /// Returns `true` if the Classified rational is [`FractionLike`].
+    ///
+    /// [`FractionLike`]: CatRational::FractionLike
+    #[must_use]
+    pub fn is_fraction_like(&self) -> bool {
+        matches!(self, Self::FractionLike(..))
+    }
+
+    /// Returns `true` if the Classified rational is [`IntegerAndFractionalPart`].
+    ///
+    /// [`IntegerAndFractionalPart`]: CatRational::IntegerAndFractionalPart
+    #[must_use]
+    pub fn is_integer_and_fractional_part(&self) -> bool {
+        matches!(self, Self::IntegerAndFractionalPart(..))
+    }
+}
+
+impl CatFloat {
+    /// Approximates the classified value as a reduced fraction `(numerator, denominator)`
+    /// with `denominator <= max_denominator`.
+    ///
+    /// This takes the last convergent of the value's exact continued fraction whose
+    /// denominator fits, so values like `2.75` come back exactly as `11/4` however large
+    /// `max_denominator` is. Returns `None` for [`Nan`] and [`Infinity`],
+    /// if `max_denominator` is zero, or if the numerator doesn't fit in an `i64`.
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use floating_cat::*;
+    /// assert_eq!(0.5f64.category().as_rational(100), Some((1, 2)));
+    /// assert_eq!((1.0f64 / 3.0).category().as_rational(100), Some((1, 3)));
+    /// ```
+    ///
+    /// [`Nan`]: CatFloat::Nan
+    /// [`Infinity`]: CatFloat::Infinity
+    pub fn as_rational(&self, max_denominator: u64) -> Option<(i64, u64)> {
+        if max_denominator == 0 {
+            return None;
+        }
+        let terms = exact_continued_fraction(self.to_f64())?;
+
+        // Convergents h/k, starting from the seeds 1/0 and 0/1.
+        let (mut h, mut h_prev) = (1i128, 0i128);
+        let (mut k, mut k_prev) = (0i128, 1i128);
+        for a in terms {
+            let next = |p: i128, q: i128| a.checked_mul(p)?.checked_add(q);
+            let (Some(next_h), Some(next_k)) = (next(h, h_prev), next(k, k_prev)) else {
+                break;
+            };
+            if next_k > i128::from(max_denominator) {
+                break;
+            }
+            (h, h_prev, k, k_prev) = (next_h, h, next_k, k);
+        }
+
+        if k == 0 {
+            // Not even the first convergent fit, which only happens for huge values.
+            return None;
+        }
+        Some((i64::try_from(h).ok()?, k as u64))
+    }
+
+    /// Formats the value as a mixed number, like `"1 1/2"`, using [`as_rational`] with the
+    /// same `max_denominator`.
+    ///
+    /// Values below one drop the whole part, like `"3/4"`, and whole values drop the
+    /// fraction, like `"3"`. Negatives get a single leading `-`, as in `"-2 1/4"`.
+    /// Returns `None` wherever [`as_rational`] does.
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use floating_cat::*;
+    /// assert_eq!(1.5f64.category().to_mixed_number_string(100).as_deref(), Some("1 1/2"));
+    /// assert_eq!(0.75f64.category().to_mixed_number_string(100).as_deref(), Some("3/4"));
+    /// ```
+    ///
+    /// [`as_rational`]: CatFloat::as_rational
+    pub fn to_mixed_number_string(&self, max_denominator: u64) -> Option<String> {
+        let (numerator, denominator) = self.as_rational(max_denominator)?;
+        let sign = if numerator < 0 { "-" } else { "" };
+        let numerator = numerator.unsigned_abs();
+        let (whole, rest) = (numerator / denominator, numerator % denominator);
+
+        Some(match (whole, rest) {
+            (_, 0) => format!("{sign}{whole}"),
+            (0, _) => format!("{sign}{rest}/{denominator}"),
+            _ => format!("{sign}{whole} {rest}/{denominator}"),
+        })
+    }
+}
+
+impl Category for BigRational {
+    type D = CatRational;
+    fn category(&self) -> Self::D {
+        if self.is_integer() {
+            return CatRational::IntegerLike(self.to_integer());
+        }
+
+        let int_part: BigInt = self.to_integer();
+        let fract_part: BigRational = self.fract();
+
+        if int_part.is_zero() {
+            CatRational::FractionLike(fract_part)
+        } else {
+            CatRational::IntegerAndFractionalPart(int_part, fract_part)
+        }
+    }
+}
+
+#[test]
+fn rationals_are_classified() {
+    let ratio = |n: i64, d: i64| BigRational::new(n.into(), d.into());
+
+    assert_eq!(
+        ratio(3, 2).category(),
+        CatRational::IntegerAndFractionalPart(1.into(), ratio(1, 2))
+    );
+    assert_eq!(ratio(4, 1).category(), CatRational::IntegerLike(4.into()));
+    assert_eq!(
+        ratio(-1, 3).category(),
+        CatRational::FractionLike(ratio(-1, 3))
+    );
+    assert_eq!(
+        ratio(-7, 2).category(),
+        CatRational::IntegerAndFractionalPart((-3).into(), ratio(-1, 2))
+    );
+    assert_eq!(ratio(0, 5).category(), CatRational::IntegerLike(0.into()));
+}
+
+#[test]
+fn floats_approximate_as_rationals() {
+    assert_eq!(0.5f64.category().as_rational(100), Some((1, 2)));
+    assert_eq!((1.0f64 / 3.0).category().as_rational(100), Some((1, 3)));
+    assert_eq!((-0.5f64).category().as_rational(100), Some((-1, 2)));
+    assert_eq!(2.75f64.category().as_rational(100), Some((11, 4)));
+    assert_eq!(3.0f64.category().as_rational(1), Some((3, 1)));
+    assert_eq!(0.0f64.category().as_rational(10), Some((0, 1)));
+    assert_eq!(
+        core::f64::consts::PI.category().as_rational(1000),
+        Some((355, 113))
+    );
+    assert_eq!(f64::NAN.category().as_rational(100), None);
+    assert_eq!(f64::INFINITY.category().as_rational(100), None);
+    assert_eq!(0.5f64.category().as_rational(0), None);
+    assert_eq!(1e300f64.category().as_rational(100), None);
+    assert!((1.0f64 / 3.0).category().as_rational(u64::MAX).is_some());
+
+    // Exact values stop at their own expansion, however large the limit.
+    assert_eq!(2.75f64.category().as_rational(u64::MAX), Some((11, 4)));
+    assert_eq!(0.75f64.category().as_rational(u64::MAX), Some((3, 4)));
+    assert_eq!((-0.75f64).category().as_rational(u64::MAX), Some((-3, 4)));
+    assert_eq!(
+        0.1f64.category().as_rational(u64::MAX),
+        Some((3602879701896397, 36028797018963968))
+    );
+    assert_eq!(5e-324f64.category().as_rational(u64::MAX), Some((0, 1)));
+    assert_eq!((-5e-324f64).category().as_rational(u64::MAX), Some((0, 1)));
+}
+
+#[test]
+fn mixed_numbers_are_formatted() {
+    let mixed = |x: f64| x.category().to_mixed_number_string(100);
+    assert_eq!(mixed(1.5).as_deref(), Some("1 1/2"));
+    assert_eq!(mixed(0.75).as_deref(), Some("3/4"));
+    assert_eq!(mixed(-2.25).as_deref(), Some("-2 1/4"));
+    assert_eq!(mixed(-0.5).as_deref(), Some("-1/2"));
+    assert_eq!(mixed(3.0).as_deref(), Some("3"));
+    assert_eq!(mixed(-0.0).as_deref(), Some("0"));
+    assert_eq!(
+        2.75f64
+            .category()
+            .to_mixed_number_string(u64::MAX)
+            .as_deref(),
+        Some("2 3/4")
+    );
+    assert_eq!(
+        (-0.75f64)
+            .category()
+            .to_mixed_number_string(u64::MAX)
+            .as_deref(),
+        Some("-3/4")
+    );
+    assert_eq!(mixed(f64::NAN), None);
+    assert_eq!(mixed(f64::NEG_INFINITY), None);
+}
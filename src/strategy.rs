@@ -0,0 +1,67 @@
+//! `proptest` strategies for floats, behind the `proptest` feature.
+
+use proptest::prelude::*;
+
+#[cfg(test)]
+use crate::{CatFloat, Category};
+
+/// Any finite `f64`, including zeros and subnormals.
+///
+/// Half the values are drawn from just around 2^52 and 2^53, where floats stop having
+/// fractional bits and edge cases in splitting cluster.
+pub fn any_finite_f64() -> impl Strategy<Value = f64> {
+    use proptest::num::f64::{NEGATIVE, NORMAL, POSITIVE, SUBNORMAL, ZERO};
+
+    let near_boundary = (
+        prop_oneof![Just(52), Just(53)],
+        -4096i64..4096,
+        any::<bool>(),
+    )
+        .prop_map(|(exponent, offset, negative)| {
+            let boundary = (1u64 << exponent) as f64;
+            let x = f64::from_bits(boundary.to_bits().wrapping_add_signed(offset));
+            if negative {
+                -x
+            } else {
+                x
+            }
+        });
+
+    prop_oneof![
+        POSITIVE | NEGATIVE | NORMAL | SUBNORMAL | ZERO,
+        near_boundary,
+    ]
+}
+
+#[cfg(test)]
+proptest! {
+    #[test]
+    fn finite_values_round_trip(x in any_finite_f64()) {
+        prop_assert_eq!(x.category().to_f64().to_bits(), x.to_bits());
+    }
+
+    #[test]
+    fn mixed_values_have_both_parts(x in any_finite_f64()) {
+        if let CatFloat::IntegerAndFractionalPart(int, fract) = x.category() {
+            prop_assert!(int != 0.0);
+            prop_assert!(fract != 0.0);
+            prop_assert!(fract.abs() < 1.0);
+            prop_assert_eq!(int + fract, x);
+        }
+    }
+
+    #[test]
+    fn fraction_like_has_no_integer_part(x in any_finite_f64()) {
+        if let CatFloat::FractionLike(n) = x.category() {
+            prop_assert!(n.abs() < 1.0);
+            prop_assert_eq!(n.trunc(), 0.0);
+        }
+    }
+
+    #[test]
+    fn integer_like_has_no_fractional_part(x in any_finite_f64()) {
+        if let CatFloat::IntegerLike(n) = x.category() {
+            prop_assert_eq!(n.fract(), 0.0);
+        }
+    }
+}
@@ -0,0 +1,69 @@
+//! A visitor over the variants of [`CatFloat`], for reusable handlers.
+
+#[cfg(doc)]
+use crate::CatFloat;
+use crate::{NanKind, Sign};
+
+/// Handles each variant of a [`CatFloat`]; pass one to [`CatFloat::accept`].
+///
+/// The five core variants must be handled. The rest default to the closest core variant:
+/// zeros to [`on_integer_like`], subnormals to [`on_fraction_like`], and huge integers to
+/// [`on_integer_like`].
+///
+/// # Examples:
+/// ```rust
+/// # use floating_cat::*;
+/// struct Describe;
+///
+/// impl CatFloatVisitor for Describe {
+///     type Output = &'static str;
+///     fn on_integer_like(&mut self, _: f64) -> Self::Output { "integer" }
+///     fn on_fraction_like(&mut self, _: f64) -> Self::Output { "fraction" }
+///     fn on_mixed(&mut self, _: f64, _: f64) -> Self::Output { "mixed" }
+///     fn on_nan(&mut self, _: NanKind, _: u64) -> Self::Output { "nan" }
+///     fn on_infinity(&mut self, _: Sign) -> Self::Output { "infinity" }
+/// }
+///
+/// assert_eq!(1.5f64.category().accept(&mut Describe), "mixed");
+/// assert_eq!(0.0f64.category().accept(&mut Describe), "integer");
+/// ```
+///
+/// [`on_integer_like`]: CatFloatVisitor::on_integer_like
+/// [`on_fraction_like`]: CatFloatVisitor::on_fraction_like
+pub trait CatFloatVisitor {
+    /// What each handler returns.
+    type Output;
+
+    /// Handles [`CatFloat::IntegerLike`].
+    fn on_integer_like(&mut self, n: f64) -> Self::Output;
+
+    /// Handles [`CatFloat::FractionLike`].
+    fn on_fraction_like(&mut self, n: f64) -> Self::Output;
+
+    /// Handles [`CatFloat::IntegerAndFractionalPart`].
+    fn on_mixed(&mut self, int: f64, fract: f64) -> Self::Output;
+
+    /// Handles [`CatFloat::Nan`].
+    fn on_nan(&mut self, kind: NanKind, payload: u64) -> Self::Output;
+
+    /// Handles [`CatFloat::Infinity`].
+    fn on_infinity(&mut self, sign: Sign) -> Self::Output;
+
+    /// Handles [`CatFloat::Zero`], as an integer-like `0.0` or `-0.0` by default.
+    fn on_zero(&mut self, sign: Sign) -> Self::Output {
+        match sign {
+            Sign::Positive => self.on_integer_like(0.0),
+            Sign::Negative => self.on_integer_like(-0.0),
+        }
+    }
+
+    /// Handles [`CatFloat::Subnormal`], as a fraction-like by default.
+    fn on_subnormal(&mut self, n: f64) -> Self::Output {
+        self.on_fraction_like(n)
+    }
+
+    /// Handles [`CatFloat::HugeInteger`], as an integer-like by default.
+    fn on_huge_integer(&mut self, n: f64) -> Self::Output {
+        self.on_integer_like(n)
+    }
+}